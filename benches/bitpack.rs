@@ -0,0 +1,104 @@
+use bit_ops::bitops_u64;
+use bit_ops::bitpack::{BLOCK_LEN, pack_u32, unpack_u32};
+use criterion::{Criterion, criterion_group, criterion_main};
+use rand::Rng;
+use std::hint::black_box;
+
+/// Amount of blocks per iteration.
+const BLOCKS: usize = 1_000;
+
+fn get_random_blocks(bits: u32) -> Box<[[u32; BLOCK_LEN]]> {
+    let mut rng = rand::rng();
+    let mask = bitops_u64::create_mask(u64::from(bits)) as u32;
+    (0..BLOCKS)
+        .map(|_| core::array::from_fn(|_| rng.random::<u32>() & mask))
+        .collect()
+}
+
+/// Naive packing of a block, one value at a time via `set_bits`.
+fn pack_naive(bits: u32, input: &[u32; BLOCK_LEN], output: &mut [u64]) {
+    let bits = u64::from(bits);
+    output[..bits as usize].fill(0);
+    for (i, &value) in input.iter().enumerate() {
+        let value = u64::from(value);
+        let bit = i as u64 * bits;
+        let word = (bit / 64) as usize;
+        let shift = bit % 64;
+        if shift + bits <= 64 {
+            output[word] = bitops_u64::set_bits(output[word], value, bits, shift);
+        } else {
+            let low_bits = 64 - shift;
+            output[word] = bitops_u64::set_bits(output[word], value, low_bits, shift);
+            output[word + 1] =
+                bitops_u64::set_bits(output[word + 1], value >> low_bits, bits - low_bits, 0);
+        }
+    }
+}
+
+/// Naive unpacking of a block, one value at a time via `get_bits`.
+fn unpack_naive(bits: u32, input: &[u64], output: &mut [u32; BLOCK_LEN]) {
+    let bits = u64::from(bits);
+    for (i, value) in output.iter_mut().enumerate() {
+        let bit = i as u64 * bits;
+        let word = (bit / 64) as usize;
+        let shift = bit % 64;
+        *value = if shift + bits <= 64 {
+            bitops_u64::get_bits(input[word], bits, shift) as u32
+        } else {
+            let low_bits = 64 - shift;
+            let low = bitops_u64::get_bits(input[word], low_bits, shift);
+            let high = bitops_u64::get_bits(input[word + 1], bits - low_bits, 0);
+            (low | (high << low_bits)) as u32
+        };
+    }
+}
+
+fn bench_width<const BITS: u32>(c: &mut Criterion) {
+    let blocks = get_random_blocks(BITS);
+    let mut packed = vec![0_u64; BLOCKS * BITS as usize].into_boxed_slice();
+    let mut unpacked = [0; BLOCK_LEN];
+
+    c.bench_function(&format!("pack_u32_{BITS}bits_kernel"), |b| {
+        b.iter(|| {
+            for (block, output) in blocks.iter().zip(packed.chunks_exact_mut(BITS as usize)) {
+                pack_u32::<BITS>(black_box(block), output);
+            }
+            black_box(&packed);
+        })
+    });
+    c.bench_function(&format!("pack_u32_{BITS}bits_naive"), |b| {
+        b.iter(|| {
+            for (block, output) in blocks.iter().zip(packed.chunks_exact_mut(BITS as usize)) {
+                pack_naive(BITS, black_box(block), output);
+            }
+            black_box(&packed);
+        })
+    });
+    c.bench_function(&format!("unpack_u32_{BITS}bits_kernel"), |b| {
+        b.iter(|| {
+            for input in packed.chunks_exact(BITS as usize) {
+                unpack_u32::<BITS>(black_box(input), &mut unpacked);
+                black_box(&unpacked);
+            }
+        })
+    });
+    c.bench_function(&format!("unpack_u32_{BITS}bits_naive"), |b| {
+        b.iter(|| {
+            for input in packed.chunks_exact(BITS as usize) {
+                unpack_naive(BITS, black_box(input), &mut unpacked);
+                black_box(&unpacked);
+            }
+        })
+    });
+}
+
+fn bench_bitpack(c: &mut Criterion) {
+    bench_width::<1>(c);
+    bench_width::<5>(c);
+    bench_width::<12>(c);
+    bench_width::<17>(c);
+    bench_width::<32>(c);
+}
+
+criterion_group!(benches, bench_bitpack);
+criterion_main!(benches);
@@ -0,0 +1,201 @@
+//! Module providing an iterator that decomposes a range into naturally aligned
+//! power-of-two blocks.
+//!
+//! See [`AlignedBlocksIter`].
+
+use core::iter::FusedIterator;
+
+/// Iterator that decomposes the range `[start, end)` into the minimal sequence
+/// of naturally aligned blocks with a power-of-two size.
+///
+/// Each item is a pair `(base, order)`, describing the block
+/// `[base, base + 2^order)`, where `base` is a multiple of `2^order`. The
+/// blocks are emitted in ascending order and cover the range without gaps.
+/// This is useful, for example, to program IOMMU invalidations or MTRRs. An
+/// optional maximum order limits the size of the blocks, e.g., to the largest
+/// supported page size.
+///
+/// The iterator can be used with [`u8`], [`u16`], [`u32`], [`u64`], [`u128`],
+/// and [`usize`]. It is built on top of `largest_aligned_block` of the
+/// Function API.
+///
+/// # Example
+/// ```rust
+/// # use bit_ops::AlignedBlocksIter;
+/// // also works with u8, u16, u32, u128, and usize
+/// let iter = AlignedBlocksIter::<u64>::new(0x1000, 0x7000);
+/// assert_eq!(
+///     &iter.collect::<Vec<_>>(),
+///     &[(0x1000, 12), (0x2000, 13), (0x4000, 13), (0x6000, 12)]
+/// );
+///
+/// let iter = AlignedBlocksIter::<u64>::with_max_order(0x0, 0x4000, 12);
+/// assert_eq!(
+///     &iter.collect::<Vec<_>>(),
+///     &[(0x0, 12), (0x1000, 12), (0x2000, 12), (0x3000, 12)]
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct AlignedBlocksIter<U> {
+    /// Start of the remaining range.
+    start: U,
+    /// Exclusive end of the range.
+    end: U,
+    /// Maximum order of the emitted blocks.
+    max_order: U,
+}
+
+/// Implements [`AlignedBlocksIter`] for the specified primitive type.
+macro_rules! impl_aligned_blocks_iter {
+    ($primitive_ty:ty) => {
+        impl AlignedBlocksIter<$primitive_ty> {
+            /// Creates a new iterator over the blocks of the range
+            /// `[start, end)`.
+            ///
+            /// If `start >= end`, the iterator is empty.
+            #[must_use]
+            pub const fn new(start: $primitive_ty, end: $primitive_ty) -> Self {
+                Self::with_max_order(start, end, <$primitive_ty>::BITS as $primitive_ty)
+            }
+
+            /// Creates a new iterator over the blocks of the range
+            /// `[start, end)`, where no block is larger than `2^max_order`.
+            ///
+            /// If `start >= end`, the iterator is empty.
+            #[must_use]
+            pub const fn with_max_order(
+                start: $primitive_ty,
+                end: $primitive_ty,
+                max_order: $primitive_ty,
+            ) -> Self {
+                Self {
+                    start,
+                    end,
+                    max_order,
+                }
+            }
+        }
+
+        impl Iterator for AlignedBlocksIter<$primitive_ty> {
+            type Item = ($primitive_ty, $primitive_ty);
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                if self.start >= self.end {
+                    return None;
+                }
+                let base = self.start;
+                paste::paste! {
+                    let order = $crate::[< bitops _ $primitive_ty >]::largest_aligned_block(
+                        base,
+                        self.end - base,
+                    )?;
+                }
+                let order = order.min(self.max_order);
+                // Can't overflow: the block ends at or before `end`.
+                self.start = base + (1 << order);
+                Some((base, order))
+            }
+        }
+
+        impl FusedIterator for AlignedBlocksIter<$primitive_ty> {}
+    };
+}
+
+impl_aligned_blocks_iter!(u8);
+impl_aligned_blocks_iter!(u16);
+impl_aligned_blocks_iter!(u32);
+impl_aligned_blocks_iter!(u64);
+impl_aligned_blocks_iter!(u128);
+impl_aligned_blocks_iter!(usize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::vec::Vec;
+
+    #[test]
+    fn empty() {
+        assert_eq!(AlignedBlocksIter::<u8>::new(0, 0).next(), None);
+        assert_eq!(AlignedBlocksIter::<u8>::new(5, 5).next(), None);
+        assert_eq!(AlignedBlocksIter::<u8>::new(6, 5).next(), None);
+    }
+
+    #[test]
+    fn aligned_blocks_iter() {
+        let iter = AlignedBlocksIter::<u8>::new(0, u8::MAX);
+        assert_eq!(
+            &iter.collect::<Vec<_>>(),
+            &[
+                (0, 7),
+                (128, 6),
+                (192, 5),
+                (224, 4),
+                (240, 3),
+                (248, 2),
+                (252, 1),
+                (254, 0)
+            ]
+        );
+
+        let iter = AlignedBlocksIter::<u8>::new(3, 17);
+        assert_eq!(
+            &iter.collect::<Vec<_>>(),
+            &[(3, 0), (4, 2), (8, 3), (16, 0)]
+        );
+
+        let iter = AlignedBlocksIter::<u8>::with_max_order(0, 64, 4);
+        assert_eq!(
+            &iter.collect::<Vec<_>>(),
+            &[(0, 4), (16, 4), (32, 4), (48, 4)]
+        );
+
+        let iter = AlignedBlocksIter::<u8>::with_max_order(1, 3, 0);
+        assert_eq!(&iter.collect::<Vec<_>>(), &[(1, 0), (2, 0)]);
+    }
+
+    /// Checks for all ranges that the blocks are aligned, cover the range
+    /// without gaps, and that the decomposition is minimal.
+    #[test]
+    fn exhaustive_u8() {
+        for end in 0..=u8::MAX {
+            // Reference: `min_blocks[x]` is the minimal number of aligned
+            // blocks covering `[x, end)`, computed by dynamic programming.
+            let mut min_blocks = [0_u32; 256];
+            for x in (0..end as usize).rev() {
+                min_blocks[x] = (0..8)
+                    .filter(|order| x % (1 << order) == 0 && x + (1 << order) <= end as usize)
+                    .map(|order| 1 + min_blocks[x + (1 << order)])
+                    .min()
+                    .unwrap();
+            }
+
+            for start in 0..=end {
+                let mut next = start as u16;
+                let mut count = 0;
+                for (base, order) in AlignedBlocksIter::<u8>::new(start, end) {
+                    assert_eq!(base as u16, next);
+                    assert_eq!(base % (1 << order), 0);
+                    next += 1 << order;
+                    count += 1;
+                }
+                assert_eq!(next, end as u16);
+                assert_eq!(count, min_blocks[start as usize], "[{start}, {end})");
+            }
+        }
+    }
+
+    #[test]
+    fn aligned_blocks_iter_u64() {
+        // 2 MiB + 8 KiB starting at 1 GiB - 4 KiB
+        let start = 0x3fff_f000_u64;
+        let iter = AlignedBlocksIter::<u64>::with_max_order(start, start + 0x20_2000, 21);
+        assert_eq!(
+            &iter.collect::<Vec<_>>(),
+            &[(0x3fff_f000, 12), (0x4000_0000, 21), (0x4020_0000, 12)]
+        );
+
+        let iter = AlignedBlocksIter::<u64>::new(0, u64::MAX);
+        assert_eq!(iter.count(), 64);
+    }
+}
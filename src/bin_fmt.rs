@@ -0,0 +1,287 @@
+//! Module providing `no_std` formatters to pretty-print raw bits.
+//!
+//! See [`BinFmt`] and [`FieldDiagram`].
+
+use core::fmt::{self, Display, Formatter};
+
+/// [`Display`] wrapper printing an unsigned integer in binary with all
+/// leading zeroes and optional digit grouping.
+///
+/// The groups are counted from the LSB. With the alternate flag (`{:#}`), the
+/// output is prefixed with `0b`.
+///
+/// The wrapper can be used with [`u8`], [`u16`], [`u32`], [`u64`], [`u128`],
+/// and [`usize`].
+///
+/// # Example
+/// ```rust
+/// use bit_ops::BinFmt;
+///
+/// let value = 0x1f_u16;
+/// assert_eq!(BinFmt::new(value).to_string(), "0000000000011111");
+/// assert_eq!(
+///     BinFmt::new(value).group(4).to_string(),
+///     "0000_0000_0001_1111"
+/// );
+/// assert_eq!(
+///     format!("{:#}", BinFmt::new(value).group(8).separator(' ')),
+///     "0b00000000 00011111"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct BinFmt<U> {
+    value: U,
+    /// Amount of digits per group. `0` disables grouping.
+    group: u32,
+    separator: char,
+}
+
+impl<U> BinFmt<U> {
+    /// Creates a new wrapper without digit grouping.
+    ///
+    /// The separator defaults to `_`.
+    #[must_use]
+    pub const fn new(value: U) -> Self {
+        Self {
+            value,
+            group: 0,
+            separator: '_',
+        }
+    }
+
+    /// Sets the amount of digits per group. `0` disables grouping.
+    #[must_use]
+    pub const fn group(mut self, digits: u32) -> Self {
+        self.group = digits;
+        self
+    }
+
+    /// Sets the separator between two groups.
+    #[must_use]
+    pub const fn separator(mut self, separator: char) -> Self {
+        self.separator = separator;
+        self
+    }
+}
+
+/// Field description for [`FieldDiagram`] as `(name, bits, shift)`.
+///
+/// `bits` and `shift` have the same meaning as in the Function API, e.g., in
+/// `get_bits`.
+pub type DiagramField<'a, U> = (&'a str, U, U);
+
+/// [`Display`] wrapper printing an ASCII table of the bit fields of a value.
+///
+/// For each field, the table shows its bit range (`high:low`), its raw value
+/// in binary, and its value in hexadecimal. The fields are printed in the
+/// given order.
+///
+/// The formatter can be used with [`u8`], [`u16`], [`u32`], [`u64`], [`u128`],
+/// and [`usize`].
+///
+/// # Example
+/// ```rust
+/// use bit_ops::FieldDiagram;
+///
+/// // IOAPIC redirection table entry
+/// let entry = 0x0300_0000_0001_0930_u64;
+/// let fields = [
+///     ("vector", 8, 0),
+///     ("delivery_mode", 3, 8),
+///     ("dest_mode", 1, 11),
+///     ("masked", 1, 16),
+///     ("destination", 8, 56),
+/// ];
+///
+/// let expected = "\
+/// +---------------+-------+------------+------+
+/// | Field         | Bits  | Raw        | Hex  |
+/// +---------------+-------+------------+------+
+/// | vector        |   7:0 | 0b00110000 | 0x30 |
+/// | delivery_mode |  10:8 | 0b001      | 0x1  |
+/// | dest_mode     |    11 | 0b1        | 0x1  |
+/// | masked        |    16 | 0b1        | 0x1  |
+/// | destination   | 63:56 | 0b00000011 | 0x03 |
+/// +---------------+-------+------------+------+
+/// ";
+/// assert_eq!(FieldDiagram::new(entry, &fields).to_string(), expected);
+/// ```
+///
+/// # Panics
+///
+/// Formatting panics for fields that are outside the range of the underlying
+/// type.
+#[derive(Debug, Clone, Copy)]
+pub struct FieldDiagram<'a, U> {
+    value: U,
+    fields: &'a [DiagramField<'a, U>],
+}
+
+impl<'a, U> FieldDiagram<'a, U> {
+    /// Creates a new formatter for the given value and fields.
+    #[must_use]
+    pub const fn new(value: U, fields: &'a [DiagramField<'a, U>]) -> Self {
+        Self { value, fields }
+    }
+}
+
+/// Returns the amount of decimal digits of `n`.
+const fn decimal_digits(n: u32) -> usize {
+    match n.checked_ilog10() {
+        Some(log) => log as usize + 1,
+        None => 1,
+    }
+}
+
+/// Returns the width of the bit range column entry of a field.
+const fn range_width(bits: u32, shift: u32) -> usize {
+    match bits {
+        0 => 1,
+        1 => decimal_digits(shift),
+        _ => decimal_digits(shift + bits - 1) + 1 + decimal_digits(shift),
+    }
+}
+
+/// Writes a horizontal border line of the table.
+fn write_border(f: &mut Formatter<'_>, widths: [usize; 4]) -> fmt::Result {
+    for width in widths {
+        write!(f, "+{:-<1$}", "", width + 2)?;
+    }
+    writeln!(f, "+")
+}
+
+/// Writes a single table row consisting of the given field properties.
+fn write_row(
+    f: &mut Formatter<'_>,
+    widths: [usize; 4],
+    name: &str,
+    bits: u32,
+    shift: u32,
+    value: u128,
+) -> fmt::Result {
+    let [name_w, range_w, raw_w, hex_w] = widths;
+    write!(f, "| {name:<name_w$} | ")?;
+    let range_pad = range_w - range_width(bits, shift);
+    match bits {
+        0 => write!(f, "{:range_pad$}-", "")?,
+        1 => write!(f, "{:range_pad$}{shift}", "")?,
+        _ => write!(f, "{:range_pad$}{}:{shift}", "", shift + bits - 1)?,
+    }
+    let digits = bits.max(1) as usize;
+    let hex_digits = digits.div_ceil(4);
+    write!(
+        f,
+        " | 0b{value:0digits$b}{:raw_pad$} | 0x{value:0hex_digits$x}{:hex_pad$} |",
+        "",
+        "",
+        raw_pad = raw_w - 2 - digits,
+        hex_pad = hex_w - 2 - hex_digits,
+    )?;
+    writeln!(f)
+}
+
+/// Implements the formatters for the specified primitive type.
+macro_rules! impl_bin_fmt {
+    ($primitive_ty:ty) => {
+        impl Display for BinFmt<$primitive_ty> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                if f.alternate() {
+                    f.write_str("0b")?;
+                }
+                let mut i = <$primitive_ty>::BITS;
+                while i > 0 {
+                    i -= 1;
+                    let digit = if (self.value >> i) & 1 == 1 { '1' } else { '0' };
+                    write!(f, "{digit}")?;
+                    if self.group != 0 && i != 0 && i % self.group == 0 {
+                        write!(f, "{}", self.separator)?;
+                    }
+                }
+                Ok(())
+            }
+        }
+
+        impl Display for FieldDiagram<'_, $primitive_ty> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                let mut widths = ["Field".len(), "Bits".len(), "Raw".len(), "Hex".len()];
+                for &(name, bits, shift) in self.fields {
+                    let (bits, shift) = (bits as u32, shift as u32);
+                    let digits = bits.max(1) as usize;
+                    widths[0] = widths[0].max(name.chars().count());
+                    widths[1] = widths[1].max(range_width(bits, shift));
+                    widths[2] = widths[2].max(2 + digits);
+                    widths[3] = widths[3].max(2 + digits.div_ceil(4));
+                }
+
+                let [name_w, range_w, raw_w, hex_w] = widths;
+                write_border(f, widths)?;
+                writeln!(
+                    f,
+                    "| {:<name_w$} | {:<range_w$} | {:<raw_w$} | {:<hex_w$} |",
+                    "Field", "Bits", "Raw", "Hex"
+                )?;
+                write_border(f, widths)?;
+                for &(name, bits, shift) in self.fields {
+                    paste::paste! {
+                        let value = $crate::[< bitops _ $primitive_ty >]::get_bits(self.value, bits, shift);
+                    }
+                    write_row(f, widths, name, bits as u32, shift as u32, value as u128)?;
+                }
+                write_border(f, widths)
+            }
+        }
+    };
+}
+
+impl_bin_fmt!(u8);
+impl_bin_fmt!(u16);
+impl_bin_fmt!(u32);
+impl_bin_fmt!(u64);
+impl_bin_fmt!(u128);
+impl_bin_fmt!(usize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::format;
+    use std::string::ToString;
+
+    #[test]
+    fn bin_fmt() {
+        assert_eq!(BinFmt::new(0_u8).to_string(), "00000000");
+        assert_eq!(BinFmt::new(0b1010_u8).group(4).to_string(), "0000_1010");
+        assert_eq!(BinFmt::new(0b1010_u8).group(3).to_string(), "00_001_010");
+        assert_eq!(BinFmt::new(0b1010_u8).group(8).to_string(), "00001010");
+        assert_eq!(
+            format!("{:#}", BinFmt::new(u32::MAX).group(8).separator('\'')),
+            "0b11111111'11111111'11111111'11111111"
+        );
+        assert_eq!(
+            BinFmt::new(1_u128 << 127).to_string(),
+            format!("1{:0127}", 0)
+        );
+    }
+
+    #[test]
+    fn field_diagram() {
+        let fields = [("a", 0, 0), ("longer_name", 4, 4), ("b", 1, 0)];
+        let expected = "\
++-------------+------+--------+-----+
+| Field       | Bits | Raw    | Hex |
++-------------+------+--------+-----+
+| a           |    - | 0b0    | 0x0 |
+| longer_name |  7:4 | 0b1010 | 0xa |
+| b           |    0 | 0b1    | 0x1 |
++-------------+------+--------+-----+
+";
+        assert_eq!(FieldDiagram::new(0xa5_u8, &fields).to_string(), expected);
+
+        let expected = "\
++-------+------+-----+-----+
+| Field | Bits | Raw | Hex |
++-------+------+-----+-----+
++-------+------+-----+-----+
+";
+        assert_eq!(FieldDiagram::<u8>::new(0, &[]).to_string(), expected);
+    }
+}
@@ -0,0 +1,168 @@
+//! Module providing an iterator over the bit-reverse permutation of indices.
+//!
+//! See [`BitReversePermutationIter`].
+
+use core::iter::FusedIterator;
+use core::num::{Saturating, Wrapping};
+
+/// Iterator over the bit-reverse permutation of the indices `0..2^order`.
+///
+/// For each index `i` in ascending order, the iterator emits `i` with its
+/// lowest `order` bits reversed. This is the index order used by in-place
+/// (radix-2) FFT implementations.
+///
+/// The iterator can be used with [`u8`], [`u16`], [`u32`], [`u64`], [`u128`],
+/// and [`usize`], as well as with their [`Wrapping`] and [`Saturating`]
+/// variants. It is typically created via `bit_reverse_permutation` of the
+/// Function API or the Trait API.
+///
+/// # Example
+/// ```rust
+/// # use bit_ops::BitReversePermutationIter;
+/// // also works with u8, u16, u64, u128, and usize
+/// let iter = BitReversePermutationIter::<u32>::new(3);
+/// assert_eq!(&iter.collect::<Vec<_>>(), &[0, 4, 2, 6, 1, 5, 3, 7]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct BitReversePermutationIter<U> {
+    /// Next index to emit (in natural order).
+    index: U,
+    /// Last index to emit (in natural order).
+    last: U,
+    /// Amount of bits of each index.
+    order: U,
+    /// Whether `last` was already emitted.
+    done: bool,
+}
+
+/// Implements [`BitReversePermutationIter`] for the specified primitive type.
+macro_rules! impl_bit_reverse_permutation_iter {
+    ($primitive_ty:ty) => {
+        impl BitReversePermutationIter<$primitive_ty> {
+            /// Creates a new iterator over the indices `0..2^order`.
+            ///
+            /// # Panics
+            ///
+            /// This function panics if `order` is larger than the amount of
+            /// bits of the underlying type.
+            #[must_use]
+            pub const fn new(order: $primitive_ty) -> Self {
+                paste::paste! {
+                    let last = $crate::[< bitops _ $primitive_ty >]::create_mask(order);
+                }
+                Self {
+                    index: 0,
+                    last,
+                    order,
+                    done: false,
+                }
+            }
+        }
+
+        impl Iterator for BitReversePermutationIter<$primitive_ty> {
+            type Item = $primitive_ty;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                if self.done {
+                    return None;
+                }
+                let index = self.index;
+                if index == self.last {
+                    self.done = true;
+                } else {
+                    self.index += 1;
+                }
+                paste::paste! {
+                    Some($crate::[< bitops _ $primitive_ty >]::reverse_low_bits(index, self.order))
+                }
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                if self.done {
+                    return (0, Some(0));
+                }
+                let remaining = usize::try_from(self.last - self.index)
+                    .ok()
+                    .and_then(|n| n.checked_add(1));
+                (remaining.unwrap_or(usize::MAX), remaining)
+            }
+        }
+
+        impl FusedIterator for BitReversePermutationIter<$primitive_ty> {}
+    };
+    ($wrapper:ident, $primitive_ty:ty) => {
+        impl BitReversePermutationIter<$wrapper<$primitive_ty>> {
+            /// Creates a new iterator over the indices `0..2^order`.
+            ///
+            /// # Panics
+            ///
+            /// This function panics if `order` is larger than the amount of
+            /// bits of the underlying type.
+            #[must_use]
+            pub const fn new(order: $wrapper<$primitive_ty>) -> Self {
+                paste::paste! {
+                    let last = $crate::[< bitops _ $primitive_ty >]::create_mask(order.0);
+                }
+                Self {
+                    index: $wrapper(0),
+                    last: $wrapper(last),
+                    order,
+                    done: false,
+                }
+            }
+
+            /// Returns the iterator over the underlying primitive type.
+            const fn primitive(&self) -> BitReversePermutationIter<$primitive_ty> {
+                BitReversePermutationIter {
+                    index: self.index.0,
+                    last: self.last.0,
+                    order: self.order.0,
+                    done: self.done,
+                }
+            }
+        }
+
+        impl Iterator for BitReversePermutationIter<$wrapper<$primitive_ty>> {
+            type Item = $wrapper<$primitive_ty>;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                let mut iter = self.primitive();
+                let next = iter.next();
+                self.index = $wrapper(iter.index);
+                self.done = iter.done;
+                next.map($wrapper)
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.primitive().size_hint()
+            }
+        }
+
+        impl FusedIterator for BitReversePermutationIter<$wrapper<$primitive_ty>> {}
+    };
+}
+
+impl_bit_reverse_permutation_iter!(u8);
+impl_bit_reverse_permutation_iter!(u16);
+impl_bit_reverse_permutation_iter!(u32);
+impl_bit_reverse_permutation_iter!(u64);
+impl_bit_reverse_permutation_iter!(u128);
+impl_bit_reverse_permutation_iter!(usize);
+
+impl_bit_reverse_permutation_iter!(Wrapping, u8);
+impl_bit_reverse_permutation_iter!(Wrapping, u16);
+impl_bit_reverse_permutation_iter!(Wrapping, u32);
+impl_bit_reverse_permutation_iter!(Wrapping, u64);
+impl_bit_reverse_permutation_iter!(Wrapping, u128);
+impl_bit_reverse_permutation_iter!(Wrapping, usize);
+
+impl_bit_reverse_permutation_iter!(Saturating, u8);
+impl_bit_reverse_permutation_iter!(Saturating, u16);
+impl_bit_reverse_permutation_iter!(Saturating, u32);
+impl_bit_reverse_permutation_iter!(Saturating, u64);
+impl_bit_reverse_permutation_iter!(Saturating, u128);
+impl_bit_reverse_permutation_iter!(Saturating, usize);
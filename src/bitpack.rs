@@ -0,0 +1,282 @@
+//! Module providing bulk bit-packing kernels for blocks of integers with a
+//! fixed bit width.
+//!
+//! Columnar storage formats (e.g., the bit-packed encoding of Parquet or
+//! frame-of-reference encodings) store blocks of [`BLOCK_LEN`] integers with
+//! the same width. A block of `BLOCK_LEN` values with `BITS` bits each occupies
+//! exactly `BITS` [`u64`] words, so no block straddles a word boundary.
+//!
+//! - [`pack_u32`] and [`unpack_u32`] take the width as const generic. As the
+//!   width and the block length are known at compile time, the compiler can
+//!   unroll the kernels and turn the shifts into constants.
+//! - [`pack_u32_dyn`] and [`unpack_u32_dyn`] take the width at runtime and
+//!   dispatch to the specialized kernels.
+//!
+//! The same streaming kernels back [`PackedSlice::pack_from`] and
+//! [`PackedSlice::unpack_into`].
+//!
+//! Values with more than `BITS` bits are rejected with a panic rather than
+//! silently truncated, just like in [`PackedSlice`].
+//!
+//! The value `i` of a block occupies the bits `i * BITS..(i + 1) * BITS`,
+//! where the bits of the words are numbered as in one gigantic integer. This
+//! matches the layout of [`PackedSlice`].
+//!
+//! All functions are `const`.
+//!
+//! [`PackedSlice`]: crate::PackedSlice
+//! [`PackedSlice::pack_from`]: crate::PackedSlice::pack_from
+//! [`PackedSlice::unpack_into`]: crate::PackedSlice::unpack_into
+
+use crate::bitops_u64;
+
+/// Amount of values in a block.
+pub const BLOCK_LEN: usize = 64;
+
+/// Compile-time check for the supported widths, shared with [`PackedSlice`]
+/// and [`PackedArray`].
+///
+/// [`PackedSlice`]: crate::PackedSlice
+/// [`PackedArray`]: crate::PackedArray
+pub(crate) const fn assert_bits<const BITS: u32>() {
+    assert!(
+        BITS >= 1 && BITS <= u32::BITS,
+        "width must be in range 1..=32"
+    );
+}
+
+/// Packs `input` into the first `input.len() * BITS` bits of `output`.
+///
+/// Instead of setting each value individually, this streams the values
+/// through a 64-bit accumulator and writes each word only once. Bits of the
+/// last word behind the packed values are kept. This is the kernel behind
+/// [`pack_u32`] and [`PackedSlice::pack_from`].
+///
+/// # Panics
+///
+/// This function panics if a value has more than `BITS` bits or if `output`
+/// is too small.
+///
+/// [`PackedSlice::pack_from`]: crate::PackedSlice::pack_from
+#[inline]
+pub(crate) const fn pack_stream<const BITS: u32>(input: &[u32], output: &mut [u64]) {
+    let mask = bitops_u64::create_mask(BITS as u64);
+    let mut acc = 0_u64;
+    let mut acc_bits = 0;
+    let mut word = 0;
+    let mut i = 0;
+    while i < input.len() {
+        let value = input[i] as u64;
+        assert!(value <= mask, "value has more bits than the width");
+        acc |= value << acc_bits;
+        acc_bits += BITS;
+        if acc_bits >= 64 {
+            output[word] = acc;
+            word += 1;
+            acc_bits -= 64;
+            // Carry over the bits that didn't fit into the previous word.
+            acc = if acc_bits == 0 {
+                0
+            } else {
+                value >> (BITS - acc_bits)
+            };
+        }
+        i += 1;
+    }
+    if acc_bits > 0 {
+        // Keep the bits behind the packed values in the last word.
+        let mask = bitops_u64::create_mask(acc_bits as u64);
+        output[word] = (output[word] & !mask) | acc;
+    }
+}
+
+/// Unpacks the first `output.len()` values with `BITS` bits each from
+/// `input`.
+///
+/// Instead of getting each value individually, this streams the words
+/// through a 64-bit buffer and reads each word only once. This is the kernel
+/// behind [`unpack_u32`] and [`PackedSlice::unpack_into`].
+///
+/// # Panics
+///
+/// This function panics if `input` is too small.
+///
+/// [`PackedSlice::unpack_into`]: crate::PackedSlice::unpack_into
+#[inline]
+pub(crate) const fn unpack_stream<const BITS: u32>(input: &[u64], output: &mut [u32]) {
+    let mask = bitops_u64::create_mask(BITS as u64);
+    let mut buf = 0_u64;
+    let mut buf_bits = 0;
+    let mut word = 0;
+    let mut i = 0;
+    while i < output.len() {
+        if buf_bits >= BITS {
+            output[i] = (buf & mask) as u32;
+            buf >>= BITS;
+            buf_bits -= BITS;
+        } else {
+            let next = input[word];
+            word += 1;
+            let missing = BITS - buf_bits;
+            output[i] = ((buf | (next << buf_bits)) & mask) as u32;
+            buf = next >> missing;
+            buf_bits = 64 - missing;
+        }
+        i += 1;
+    }
+}
+
+/// Packs a block of values with `BITS` bits each into `BITS` words.
+///
+/// # Parameters
+///
+/// - `input`: Block of values to pack.
+/// - `output`: Destination. Only the first `BITS` words are written.
+///
+/// # Example
+///
+/// ```rust
+/// use bit_ops::bitpack::{pack_u32, unpack_u32};
+///
+/// let input = core::array::from_fn(|i| i as u32 % 8);
+/// let mut packed = [0; 3];
+/// pack_u32::<3>(&input, &mut packed);
+/// assert_eq!(packed[0] & 0o7777, 0o3210);
+///
+/// let mut output = [0; 64];
+/// unpack_u32::<3>(&packed, &mut output);
+/// assert_eq!(output, input);
+/// ```
+///
+/// # Panics
+///
+/// This function panics if a value has more than `BITS` bits or if `output`
+/// has fewer than `BITS` words.
+#[inline]
+pub const fn pack_u32<const BITS: u32>(input: &[u32; BLOCK_LEN], output: &mut [u64]) {
+    const { assert_bits::<BITS>() };
+    assert!(output.len() >= BITS as usize, "output is too small");
+    pack_stream::<BITS>(input, output);
+}
+
+/// Unpacks a block of values with `BITS` bits each from `BITS` words.
+///
+/// This is the inverse of [`pack_u32`].
+///
+/// # Parameters
+///
+/// - `input`: Packed block. Only the first `BITS` words are read.
+/// - `output`: Destination for the unpacked values.
+///
+/// # Panics
+///
+/// This function panics if `input` has fewer than `BITS` words.
+#[inline]
+pub const fn unpack_u32<const BITS: u32>(input: &[u64], output: &mut [u32; BLOCK_LEN]) {
+    const { assert_bits::<BITS>() };
+    assert!(input.len() >= BITS as usize, "input is too small");
+    unpack_stream::<BITS>(input, output);
+}
+
+/// Generates the dispatch from runtime widths to the specialized kernels.
+macro_rules! impl_dyn_dispatch {
+    ($($bits:literal),+) => {
+        /// Like [`pack_u32`] but with the width given at runtime.
+        ///
+        /// # Parameters
+        ///
+        /// - `bits`: Width of each value (`1..=32`).
+        /// - `input`: Block of values to pack.
+        /// - `output`: Destination. Only the first `bits` words are written.
+        ///
+        /// # Panics
+        ///
+        /// This function panics if `bits` is not in range `1..=32`, if a value
+        /// has more than `bits` bits, or if `output` has fewer than `bits`
+        /// words.
+        #[inline]
+        pub const fn pack_u32_dyn(bits: u32, input: &[u32; BLOCK_LEN], output: &mut [u64]) {
+            match bits {
+                $($bits => pack_u32::<$bits>(input, output),)+
+                _ => panic!("width must be in range 1..=32"),
+            }
+        }
+
+        /// Like [`unpack_u32`] but with the width given at runtime.
+        ///
+        /// # Parameters
+        ///
+        /// - `bits`: Width of each value (`1..=32`).
+        /// - `input`: Packed block. Only the first `bits` words are read.
+        /// - `output`: Destination for the unpacked values.
+        ///
+        /// # Panics
+        ///
+        /// This function panics if `bits` is not in range `1..=32` or if
+        /// `input` has fewer than `bits` words.
+        #[inline]
+        pub const fn unpack_u32_dyn(bits: u32, input: &[u64], output: &mut [u32; BLOCK_LEN]) {
+            match bits {
+                $($bits => unpack_u32::<$bits>(input, output),)+
+                _ => panic!("width must be in range 1..=32"),
+            }
+        }
+    };
+}
+
+impl_dyn_dispatch!(
+    1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26,
+    27, 28, 29, 30, 31, 32
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let mut state = 0x2545_f491_u32;
+        for bits in 1..=32 {
+            let mask = bitops_u64::create_mask(u64::from(bits)) as u32;
+            let input = core::array::from_fn(|_| {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                state & mask
+            });
+            let mut packed = [u64::MAX; 33];
+            pack_u32_dyn(bits, &input, &mut packed);
+            assert_eq!(packed[bits as usize], u64::MAX, "bits={bits}");
+
+            // Compare against element-wise packing.
+            let mut expected = [0; 32];
+            for (i, &value) in input.iter().enumerate() {
+                let value = u64::from(value);
+                let bit = i * bits as usize;
+                expected[bit / 64] |= value << (bit % 64);
+                if bit % 64 + bits as usize > 64 {
+                    expected[bit / 64 + 1] |= value >> (64 - bit % 64);
+                }
+            }
+            assert_eq!(&packed[..bits as usize], &expected[..bits as usize]);
+
+            let mut output = [0; BLOCK_LEN];
+            unpack_u32_dyn(bits, &packed, &mut output);
+            assert_eq!(output, input, "bits={bits}");
+        }
+    }
+
+    #[test]
+    #[should_panic = "more bits"]
+    fn value_too_large() {
+        let mut input = [0; BLOCK_LEN];
+        input[7] = 0b1000;
+        pack_u32::<3>(&input, &mut [0; 3]);
+    }
+
+    #[test]
+    #[should_panic = "width must be"]
+    fn invalid_width() {
+        pack_u32_dyn(33, &[0; BLOCK_LEN], &mut [0; 33]);
+    }
+}
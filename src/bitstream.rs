@@ -0,0 +1,550 @@
+//! Module providing a bit-granular reader and writer over byte slices, plus
+//! universal codes on top of them.
+//!
+//! [`BitReader`] and [`BitWriter`] use big-endian (MSB-first) bit order, as
+//! used by video bitstreams such as H.264/H.265. See the `_be` functions of
+//! [`bytes`] for details. Both support the following universal codes:
+//!
+//! - Exp-Golomb, unsigned (`ue(v)`) and signed (`se(v)`), as used for the
+//!   syntax elements of H.264/H.265 parameter sets.
+//! - Elias gamma and Elias delta codes for positive integers.
+//!
+//! All methods are `const`. Errors are reported as [`BitstreamError`]; on
+//! errors, the position of the reader or writer is left unchanged.
+//!
+//! # Example
+//! ```rust
+//! use bit_ops::bitstream::{BitReader, BitWriter};
+//!
+//! let mut buf = [0; 8];
+//! let mut writer = BitWriter::new(&mut buf);
+//! writer.write_bits(0b101, 3).unwrap();
+//! writer.write_exp_golomb(7).unwrap();
+//! writer.write_signed_exp_golomb(-2).unwrap();
+//! writer.write_elias_delta(1000).unwrap();
+//! let len = writer.bytes_written();
+//!
+//! let mut reader = BitReader::new(&buf[..len]);
+//! assert_eq!(reader.read_bits(3), Ok(0b101));
+//! assert_eq!(reader.read_exp_golomb(), Ok(7));
+//! assert_eq!(reader.read_signed_exp_golomb(), Ok(-2));
+//! assert_eq!(reader.read_elias_delta(), Ok(1000));
+//! ```
+//!
+//! [`bytes`]: crate::bytes
+
+use crate::{bitops_u64, bytes};
+use core::fmt::{self, Display, Formatter};
+
+/// Errors of [`BitReader`] and [`BitWriter`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BitstreamError {
+    /// The input ended before the end of the value.
+    UnexpectedEnd,
+    /// The output buffer is too small for the value.
+    BufferFull,
+    /// The decoded value doesn't fit into the target type.
+    Overflow,
+}
+
+impl Display for BitstreamError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnexpectedEnd => f.write_str("input ended before the end of the value"),
+            Self::BufferFull => f.write_str("output buffer is too small"),
+            Self::Overflow => f.write_str("decoded value doesn't fit into the target type"),
+        }
+    }
+}
+
+impl core::error::Error for BitstreamError {}
+
+/// Maps a signed value to the code number of the signed Exp-Golomb code.
+///
+/// `0, 1, -1, 2, -2, ...` map to `0, 1, 2, 3, 4, ...`.
+const fn signed_to_code_num(value: i64) -> u128 {
+    if value > 0 {
+        value as u128 * 2 - 1
+    } else {
+        value.unsigned_abs() as u128 * 2
+    }
+}
+
+/// Reader of single bits and bit fields from a byte slice.
+///
+/// See the [module-level documentation](self) for more details.
+#[derive(Debug, Clone)]
+pub struct BitReader<'a> {
+    bytes: &'a [u8],
+    /// Position of the next bit to read.
+    pos: usize,
+}
+
+impl<'a> BitReader<'a> {
+    /// Creates a new reader starting at the first bit of `bytes`.
+    #[must_use]
+    pub const fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, pos: 0 }
+    }
+
+    /// Returns the position of the next bit to read.
+    #[must_use]
+    pub const fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Returns the amount of bits left to read.
+    #[must_use]
+    pub const fn remaining_bits(&self) -> usize {
+        self.bytes.len() * 8 - self.pos
+    }
+
+    /// Reads a single bit.
+    ///
+    /// # Errors
+    ///
+    /// - [`BitstreamError::UnexpectedEnd`] if there are no bits left.
+    pub const fn read_bit(&mut self) -> Result<bool, BitstreamError> {
+        match self.read_bits(1) {
+            Ok(bit) => Ok(bit == 1),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Reads a field of `bits` bits, where the first bit is the most
+    /// significant one.
+    ///
+    /// # Errors
+    ///
+    /// - [`BitstreamError::UnexpectedEnd`] if there are fewer than `bits`
+    ///   bits left.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `bits > 64`.
+    pub const fn read_bits(&mut self, bits: usize) -> Result<u64, BitstreamError> {
+        assert!(bits <= 64, "a field must not be wider than 64 bits");
+        if bits > self.remaining_bits() {
+            return Err(BitstreamError::UnexpectedEnd);
+        }
+        let value = bytes::get_bits_be(self.bytes, self.pos, bits);
+        self.pos += bits;
+        Ok(value)
+    }
+
+    /// Counts the zero bits starting at the current position, without
+    /// consuming them.
+    ///
+    /// This looks at up to 64 bits at once and uses `highest_bit` instead of
+    /// testing each bit individually.
+    const fn peek_leading_zeros(&self) -> Result<usize, BitstreamError> {
+        let mut zeros = 0;
+        loop {
+            let pos = self.pos + zeros;
+            let available = self.bytes.len() * 8 - pos;
+            if available == 0 {
+                return Err(BitstreamError::UnexpectedEnd);
+            }
+            let bits = if available < 64 { available } else { 64 };
+            let chunk = bytes::get_bits_be(self.bytes, pos, bits);
+            if let Some(highest) = bitops_u64::highest_bit(chunk) {
+                return Ok(zeros + bits - 1 - highest as usize);
+            }
+            zeros += bits;
+        }
+    }
+
+    /// Reads an Elias gamma code with up to `max_zeros` leading zeroes and
+    /// returns the encoded number.
+    const fn read_gamma(&mut self, max_zeros: usize) -> Result<u128, BitstreamError> {
+        let zeros = match self.peek_leading_zeros() {
+            Ok(zeros) => zeros,
+            Err(e) => return Err(e),
+        };
+        if zeros > max_zeros {
+            return Err(BitstreamError::Overflow);
+        }
+        if 2 * zeros + 1 > self.remaining_bits() {
+            return Err(BitstreamError::UnexpectedEnd);
+        }
+        // Skip the zeroes and the leading one.
+        self.pos += zeros + 1;
+        let value = (1 << zeros) | bytes::get_bits_be(self.bytes, self.pos, zeros) as u128;
+        self.pos += zeros;
+        Ok(value)
+    }
+
+    /// Reads an unsigned Exp-Golomb code (`ue(v)`).
+    ///
+    /// # Errors
+    ///
+    /// - [`BitstreamError::UnexpectedEnd`] if the input ends within the code.
+    /// - [`BitstreamError::Overflow`] if the value doesn't fit into a [`u64`].
+    pub const fn read_exp_golomb(&mut self) -> Result<u64, BitstreamError> {
+        let start = self.pos;
+        match self.read_gamma(64) {
+            Ok(value) if value - 1 <= u64::MAX as u128 => Ok((value - 1) as u64),
+            Ok(_) => {
+                self.pos = start;
+                Err(BitstreamError::Overflow)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Reads a signed Exp-Golomb code (`se(v)`).
+    ///
+    /// # Errors
+    ///
+    /// - [`BitstreamError::UnexpectedEnd`] if the input ends within the code.
+    /// - [`BitstreamError::Overflow`] if the value doesn't fit into an [`i64`].
+    pub const fn read_signed_exp_golomb(&mut self) -> Result<i64, BitstreamError> {
+        let start = self.pos;
+        let code_num = match self.read_gamma(64) {
+            Ok(value) => value - 1,
+            Err(e) => return Err(e),
+        };
+        let magnitude = code_num.div_ceil(2);
+        if code_num % 2 == 1 && magnitude <= i64::MAX as u128 {
+            Ok(magnitude as i64)
+        } else if code_num % 2 == 0 && magnitude <= i64::MIN.unsigned_abs() as u128 {
+            Ok((magnitude as i64).wrapping_neg())
+        } else {
+            self.pos = start;
+            Err(BitstreamError::Overflow)
+        }
+    }
+
+    /// Reads an Elias gamma code.
+    ///
+    /// # Errors
+    ///
+    /// - [`BitstreamError::UnexpectedEnd`] if the input ends within the code.
+    /// - [`BitstreamError::Overflow`] if the value doesn't fit into a [`u64`].
+    pub const fn read_elias_gamma(&mut self) -> Result<u64, BitstreamError> {
+        match self.read_gamma(63) {
+            Ok(value) => Ok(value as u64),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Reads an Elias delta code.
+    ///
+    /// # Errors
+    ///
+    /// - [`BitstreamError::UnexpectedEnd`] if the input ends within the code.
+    /// - [`BitstreamError::Overflow`] if the value doesn't fit into a [`u64`].
+    pub const fn read_elias_delta(&mut self) -> Result<u64, BitstreamError> {
+        let start = self.pos;
+        let len = match self.read_gamma(6) {
+            Ok(len) if len <= 64 => len as usize,
+            Ok(_) => {
+                self.pos = start;
+                return Err(BitstreamError::Overflow);
+            }
+            Err(e) => return Err(e),
+        };
+        match self.read_bits(len - 1) {
+            Ok(low) => Ok((1 << (len - 1)) | low),
+            Err(e) => {
+                self.pos = start;
+                Err(e)
+            }
+        }
+    }
+}
+
+/// Writer of single bits and bit fields into a byte slice.
+///
+/// Bits behind the written ones are left unchanged. See the
+/// [module-level documentation](self) for more details.
+#[derive(Debug)]
+pub struct BitWriter<'a> {
+    bytes: &'a mut [u8],
+    /// Position of the next bit to write.
+    pos: usize,
+}
+
+impl<'a> BitWriter<'a> {
+    /// Creates a new writer starting at the first bit of `bytes`.
+    #[must_use]
+    pub const fn new(bytes: &'a mut [u8]) -> Self {
+        Self { bytes, pos: 0 }
+    }
+
+    /// Returns the position of the next bit to write.
+    #[must_use]
+    pub const fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Returns the amount of bytes that contain written bits.
+    #[must_use]
+    pub const fn bytes_written(&self) -> usize {
+        self.pos.div_ceil(8)
+    }
+
+    /// Returns the amount of bits that can still be written.
+    #[must_use]
+    pub const fn remaining_bits(&self) -> usize {
+        self.bytes.len() * 8 - self.pos
+    }
+
+    /// Writes a single bit.
+    ///
+    /// # Errors
+    ///
+    /// - [`BitstreamError::BufferFull`] if there is no space left.
+    pub const fn write_bit(&mut self, bit: bool) -> Result<(), BitstreamError> {
+        self.write_bits(bit as u64, 1)
+    }
+
+    /// Writes the lowest `bits` bits of `value`, starting with the most
+    /// significant one.
+    ///
+    /// # Errors
+    ///
+    /// - [`BitstreamError::BufferFull`] if there is not enough space left.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `bits > 64`.
+    pub const fn write_bits(&mut self, value: u64, bits: usize) -> Result<(), BitstreamError> {
+        assert!(bits <= 64, "a field must not be wider than 64 bits");
+        if bits > self.remaining_bits() {
+            return Err(BitstreamError::BufferFull);
+        }
+        bytes::set_bits_be(self.bytes, self.pos, bits, value);
+        self.pos += bits;
+        Ok(())
+    }
+
+    /// Writes `zeros` zero bits. The caller checks the available space.
+    const fn write_zeros(&mut self, zeros: usize) {
+        let mut remaining = zeros;
+        while remaining > 0 {
+            let bits = if remaining < 64 { remaining } else { 64 };
+            bytes::set_bits_be(self.bytes, self.pos, bits, 0);
+            self.pos += bits;
+            remaining -= bits;
+        }
+    }
+
+    /// Writes the Elias gamma code of `value` (`value >= 1`).
+    const fn write_gamma(&mut self, value: u128) -> Result<(), BitstreamError> {
+        // Position of the highest bit, i.e., the amount of leading zeroes.
+        let zeros = (u128::BITS - 1 - value.leading_zeros()) as usize;
+        if 2 * zeros + 1 > self.remaining_bits() {
+            return Err(BitstreamError::BufferFull);
+        }
+        self.write_zeros(zeros);
+        // Space was checked above. The callers pass values below `2^65`.
+        let _ = self.write_bits(1, 1);
+        let _ = self.write_bits(value as u64, zeros);
+        Ok(())
+    }
+
+    /// Writes the unsigned Exp-Golomb code (`ue(v)`) of `value`.
+    ///
+    /// The code has `2 * floor(log2(value + 1)) + 1` bits.
+    ///
+    /// # Errors
+    ///
+    /// - [`BitstreamError::BufferFull`] if there is not enough space left.
+    pub const fn write_exp_golomb(&mut self, value: u64) -> Result<(), BitstreamError> {
+        self.write_gamma(value as u128 + 1)
+    }
+
+    /// Writes the signed Exp-Golomb code (`se(v)`) of `value`.
+    ///
+    /// The values `0, 1, -1, 2, -2, ...` are written as the unsigned
+    /// Exp-Golomb codes of `0, 1, 2, 3, 4, ...`.
+    ///
+    /// # Errors
+    ///
+    /// - [`BitstreamError::BufferFull`] if there is not enough space left.
+    pub const fn write_signed_exp_golomb(&mut self, value: i64) -> Result<(), BitstreamError> {
+        self.write_gamma(signed_to_code_num(value) + 1)
+    }
+
+    /// Writes the Elias gamma code of `value`.
+    ///
+    /// The code has `2 * floor(log2(value)) + 1` bits.
+    ///
+    /// # Errors
+    ///
+    /// - [`BitstreamError::BufferFull`] if there is not enough space left.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `value` is `0`, as the code is only defined
+    /// for positive integers.
+    pub const fn write_elias_gamma(&mut self, value: u64) -> Result<(), BitstreamError> {
+        assert!(
+            value != 0,
+            "Elias codes are only defined for positive integers"
+        );
+        self.write_gamma(value as u128)
+    }
+
+    /// Writes the Elias delta code of `value`.
+    ///
+    /// The code consists of the Elias gamma code of the bit length of
+    /// `value`, followed by all bits of `value` except the leading one.
+    ///
+    /// # Errors
+    ///
+    /// - [`BitstreamError::BufferFull`] if there is not enough space left.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `value` is `0`, as the code is only defined
+    /// for positive integers.
+    pub const fn write_elias_delta(&mut self, value: u64) -> Result<(), BitstreamError> {
+        let highest = match bitops_u64::highest_bit(value) {
+            Some(highest) => highest as usize,
+            None => panic!("Elias codes are only defined for positive integers"),
+        };
+        let len = highest + 1;
+        let len_zeros = (usize::BITS - 1 - len.leading_zeros()) as usize;
+        if 2 * len_zeros + 1 + highest > self.remaining_bits() {
+            return Err(BitstreamError::BufferFull);
+        }
+        // Space was checked above.
+        let _ = self.write_gamma(len as u128);
+        let _ = self.write_bits(value, highest);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Interesting test values around powers of two.
+    fn values() -> impl Iterator<Item = u64> {
+        (0..64)
+            .flat_map(|bit| {
+                let value = 1_u64 << bit;
+                [value - 1, value, value + 1]
+            })
+            .chain([u64::MAX - 1, u64::MAX])
+    }
+
+    #[test]
+    fn read_write_bits() {
+        let mut buf = [0xff; 3];
+        let mut writer = BitWriter::new(&mut buf);
+        writer.write_bit(false).unwrap();
+        writer.write_bits(0x1234, 13).unwrap();
+        assert_eq!(writer.bytes_written(), 2);
+        assert_eq!(writer.write_bits(0, 11), Err(BitstreamError::BufferFull));
+        assert_eq!(writer.position(), 14);
+        assert_eq!(buf, [0b0100_1000, 0b1101_0011, 0xff]);
+
+        let mut reader = BitReader::new(&buf);
+        assert_eq!(reader.read_bit(), Ok(false));
+        assert_eq!(reader.read_bits(13), Ok(0x1234));
+        assert_eq!(reader.read_bits(11), Err(BitstreamError::UnexpectedEnd));
+        assert_eq!(reader.remaining_bits(), 10);
+    }
+
+    #[test]
+    fn exp_golomb() {
+        // Table 9-2 of the H.264 specification
+        let mut buf = [0; 2];
+        let mut writer = BitWriter::new(&mut buf);
+        for value in 0..4 {
+            writer.write_exp_golomb(value).unwrap();
+        }
+        // 1 010 011 00100
+        assert_eq!(buf, [0b1010_0110, 0b0100_0000]);
+
+        // Table 9-3 of the H.264 specification
+        let mut buf = [0; 2];
+        let mut writer = BitWriter::new(&mut buf);
+        for value in [0, 1, -1, 2] {
+            writer.write_signed_exp_golomb(value).unwrap();
+        }
+        assert_eq!(buf, [0b1010_0110, 0b0100_0000]);
+
+        let mut buf = [0; 64];
+        for value in values() {
+            let mut writer = BitWriter::new(&mut buf);
+            writer.write_exp_golomb(value).unwrap();
+            let len = writer.position();
+            assert_eq!(len, 2 * (value as u128 + 1).ilog2() as usize + 1);
+            let mut reader = BitReader::new(&buf);
+            assert_eq!(reader.read_exp_golomb(), Ok(value));
+            assert_eq!(reader.position(), len);
+            let mut truncated = BitReader::new(&buf[..(len - 1) / 8]);
+            assert_eq!(
+                truncated.read_exp_golomb(),
+                Err(BitstreamError::UnexpectedEnd)
+            );
+            assert_eq!(truncated.position(), 0);
+
+            for value in [value as i64, (value as i64).wrapping_neg()] {
+                let mut writer = BitWriter::new(&mut buf);
+                writer.write_signed_exp_golomb(value).unwrap();
+                let mut reader = BitReader::new(&buf);
+                assert_eq!(reader.read_signed_exp_golomb(), Ok(value));
+            }
+        }
+
+        // The code number of `i64::MIN` doesn't fit into a `u64`.
+        let mut buf = [0; 20];
+        BitWriter::new(&mut buf)
+            .write_signed_exp_golomb(i64::MIN)
+            .unwrap();
+        assert_eq!(
+            BitReader::new(&buf).read_exp_golomb(),
+            Err(BitstreamError::Overflow)
+        );
+    }
+
+    #[test]
+    fn elias() {
+        let mut buf = [0; 20];
+        for value in values().filter(|&value| value != 0) {
+            let mut writer = BitWriter::new(&mut buf);
+            writer.write_elias_gamma(value).unwrap();
+            let len = writer.position();
+            assert_eq!(len, 2 * value.ilog2() as usize + 1);
+            let mut reader = BitReader::new(&buf);
+            assert_eq!(reader.read_elias_gamma(), Ok(value));
+            assert_eq!(reader.position(), len);
+
+            let mut writer = BitWriter::new(&mut buf);
+            writer.write_elias_delta(value).unwrap();
+            let len = writer.position();
+            let bits = value.ilog2() + 1;
+            assert_eq!(len, 2 * bits.ilog2() as usize + 1 + bits as usize - 1);
+            let mut reader = BitReader::new(&buf);
+            assert_eq!(reader.read_elias_delta(), Ok(value));
+            assert_eq!(reader.position(), len);
+        }
+
+        // 1 = "1", 2 = "0100", 3 = "0101", 4 = "01100"
+        let mut buf = [0; 2];
+        let mut writer = BitWriter::new(&mut buf);
+        for value in 1..=4 {
+            writer.write_elias_delta(value).unwrap();
+        }
+        assert_eq!(buf, [0b1010_0010, 0b1011_0000]);
+
+        let mut buf = [0; 1];
+        let mut writer = BitWriter::new(&mut buf);
+        assert_eq!(
+            writer.write_elias_gamma(1 << 4),
+            Err(BitstreamError::BufferFull)
+        );
+        assert_eq!(writer.position(), 0);
+    }
+
+    #[test]
+    #[should_panic = "positive"]
+    fn elias_zero() {
+        let _ = BitWriter::new(&mut [0; 8]).write_elias_gamma(0);
+    }
+}
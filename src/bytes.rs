@@ -0,0 +1,374 @@
+//! Module providing functions to get and set bit fields at arbitrary bit
+//! offsets directly in byte slices.
+//!
+//! This is useful for network and on-disk headers, where fields are not
+//! necessarily aligned to byte boundaries. Unlike the Function API, which
+//! operates on a single primitive integer, the functions in this module operate
+//! on `&[u8]` and `&mut [u8]` of any length. A single field can be up to `64`
+//! bits wide and may straddle any number of byte boundaries.
+//!
+//! There are two flavours of bit ordering:
+//!
+//! - `_be` (big-endian / MSB-first): Bit offset `0` is the most significant bit
+//!   of `bytes[0]`. The first bit of a field is its most significant bit. This
+//!   is the "network order" used by most protocol specifications (IETF RFCs,
+//!   H.264, ...).
+//! - `_le` (little-endian / LSB-first): Bit offset `0` is the least significant
+//!   bit of `bytes[0]`. The first bit of a field is its least significant bit.
+//!   This matches the layout of little-endian integers in memory.
+//!
+//! All functions are `const`.
+
+use crate::bitops_u8;
+
+/// Maximum width of a single field in bits.
+const MAX_FIELD_BITS: usize = u64::BITS as usize;
+
+#[track_caller]
+const fn assert_field_in_range(bytes_len: usize, bit_offset: usize, bits: usize) {
+    assert!(
+        bits <= MAX_FIELD_BITS,
+        "a field must not be wider than 64 bits"
+    );
+    let end = match bit_offset.checked_add(bits) {
+        Some(end) => end,
+        None => panic!("bit offset and field width overflow"),
+    };
+    let total_bits = match bytes_len.checked_mul(8) {
+        Some(total_bits) => total_bits,
+        None => panic!("byte slice too large"),
+    };
+    assert!(end <= total_bits, "field exceeds the byte slice");
+}
+
+/// Returns the `bits`-wide field starting at `bit_offset` in big-endian
+/// (MSB-first) bit order.
+///
+/// # Parameters
+///
+/// - `bytes`: Byte slice to read the field from.
+/// - `bit_offset`: Offset of the first bit of the field, where `0` is the
+///   most significant bit of `bytes[0]`.
+/// - `bits`: Width of the field (`0..=64`).
+///
+/// # Example
+///
+/// ```rust
+/// use bit_ops::bytes::get_bits_be;
+///
+/// let header = [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1f, 0xff];
+/// // 13-bit field starting at bit offset 51
+/// assert_eq!(get_bits_be(&header, 51, 13), 0x1fff);
+/// assert_eq!(get_bits_be(&[0b1010_0000], 0, 3), 0b101);
+/// ```
+///
+/// # Panics
+///
+/// This function panics if `bits > 64` or if the field exceeds the slice.
+#[must_use]
+#[inline]
+pub const fn get_bits_be(bytes: &[u8], bit_offset: usize, bits: usize) -> u64 {
+    assert_field_in_range(bytes.len(), bit_offset, bits);
+    let mut value = 0_u64;
+    let mut pos = bit_offset;
+    let mut remaining = bits;
+    while remaining > 0 {
+        let bit_in_byte = pos % 8;
+        let available = 8 - bit_in_byte;
+        let take = if available < remaining {
+            available
+        } else {
+            remaining
+        };
+        let chunk = bitops_u8::get_bits(bytes[pos / 8], take as u8, (available - take) as u8);
+        value = (value << take) | chunk as u64;
+        pos += take;
+        remaining -= take;
+    }
+    value
+}
+
+/// Writes the lowest `bits` bits of `value` into the field starting at
+/// `bit_offset` in big-endian (MSB-first) bit order.
+///
+/// All bits outside the field remain untouched. Bits of `value` above `bits`
+/// are ignored.
+///
+/// # Parameters
+///
+/// - `bytes`: Byte slice to write the field into.
+/// - `bit_offset`: Offset of the first bit of the field, where `0` is the
+///   most significant bit of `bytes[0]`.
+/// - `bits`: Width of the field (`0..=64`).
+/// - `value`: New value of the field.
+///
+/// # Example
+///
+/// ```rust
+/// use bit_ops::bytes::{get_bits_be, set_bits_be};
+///
+/// let mut header = [0xff; 9];
+/// set_bits_be(&mut header, 51, 13, 0);
+/// assert_eq!(header, [0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xe0, 0x00, 0xff]);
+///
+/// set_bits_be(&mut header, 51, 13, 0x1234);
+/// assert_eq!(get_bits_be(&header, 51, 13), 0x1234);
+/// ```
+///
+/// # Panics
+///
+/// This function panics if `bits > 64` or if the field exceeds the slice.
+#[inline]
+pub const fn set_bits_be(bytes: &mut [u8], bit_offset: usize, bits: usize, value: u64) {
+    assert_field_in_range(bytes.len(), bit_offset, bits);
+    let mut pos = bit_offset;
+    let mut remaining = bits;
+    while remaining > 0 {
+        let bit_in_byte = pos % 8;
+        let available = 8 - bit_in_byte;
+        let take = if available < remaining {
+            available
+        } else {
+            remaining
+        };
+        let chunk = (value >> (remaining - take)) as u8;
+        bytes[pos / 8] =
+            bitops_u8::set_bits_exact(bytes[pos / 8], chunk, take as u8, (available - take) as u8);
+        pos += take;
+        remaining -= take;
+    }
+}
+
+/// Returns the `bits`-wide field starting at `bit_offset` in little-endian
+/// (LSB-first) bit order.
+///
+/// # Parameters
+///
+/// - `bytes`: Byte slice to read the field from.
+/// - `bit_offset`: Offset of the first bit of the field, where `0` is the
+///   least significant bit of `bytes[0]`.
+/// - `bits`: Width of the field (`0..=64`).
+///
+/// # Example
+///
+/// ```rust
+/// use bit_ops::bytes::get_bits_le;
+///
+/// let raw = 0xdead_beef_u32.to_le_bytes();
+/// assert_eq!(get_bits_le(&raw, 4, 16), 0xdbee);
+/// assert_eq!(get_bits_le(&raw, 16, 16), 0xdead);
+/// assert_eq!(get_bits_le(&[0b0000_0101], 0, 3), 0b101);
+/// ```
+///
+/// # Panics
+///
+/// This function panics if `bits > 64` or if the field exceeds the slice.
+#[must_use]
+#[inline]
+pub const fn get_bits_le(bytes: &[u8], bit_offset: usize, bits: usize) -> u64 {
+    assert_field_in_range(bytes.len(), bit_offset, bits);
+    let mut value = 0_u64;
+    let mut pos = bit_offset;
+    let mut done = 0;
+    while done < bits {
+        let bit_in_byte = pos % 8;
+        let available = 8 - bit_in_byte;
+        let remaining = bits - done;
+        let take = if available < remaining {
+            available
+        } else {
+            remaining
+        };
+        let chunk = bitops_u8::get_bits(bytes[pos / 8], take as u8, bit_in_byte as u8);
+        value |= (chunk as u64) << done;
+        pos += take;
+        done += take;
+    }
+    value
+}
+
+/// Writes the lowest `bits` bits of `value` into the field starting at
+/// `bit_offset` in little-endian (LSB-first) bit order.
+///
+/// All bits outside the field remain untouched. Bits of `value` above `bits`
+/// are ignored.
+///
+/// # Parameters
+///
+/// - `bytes`: Byte slice to write the field into.
+/// - `bit_offset`: Offset of the first bit of the field, where `0` is the
+///   least significant bit of `bytes[0]`.
+/// - `bits`: Width of the field (`0..=64`).
+/// - `value`: New value of the field.
+///
+/// # Example
+///
+/// ```rust
+/// use bit_ops::bytes::set_bits_le;
+///
+/// let mut raw = [0; 4];
+/// set_bits_le(&mut raw, 16, 16, 0xdead);
+/// set_bits_le(&mut raw, 0, 16, 0xbeef);
+/// assert_eq!(u32::from_le_bytes(raw), 0xdead_beef);
+/// ```
+///
+/// # Panics
+///
+/// This function panics if `bits > 64` or if the field exceeds the slice.
+#[inline]
+pub const fn set_bits_le(bytes: &mut [u8], bit_offset: usize, bits: usize, value: u64) {
+    assert_field_in_range(bytes.len(), bit_offset, bits);
+    let mut pos = bit_offset;
+    let mut done = 0;
+    while done < bits {
+        let bit_in_byte = pos % 8;
+        let available = 8 - bit_in_byte;
+        let remaining = bits - done;
+        let take = if available < remaining {
+            available
+        } else {
+            remaining
+        };
+        let chunk = (value >> done) as u8;
+        bytes[pos / 8] =
+            bitops_u8::set_bits_exact(bytes[pos / 8], chunk, take as u8, bit_in_byte as u8);
+        pos += take;
+        done += take;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn get_bits_be() {
+        let bytes = [0b1010_1100, 0b0101_0011];
+        assert_eq!(super::get_bits_be(&bytes, 0, 0), 0);
+        assert_eq!(super::get_bits_be(&bytes, 16, 0), 0);
+        assert_eq!(super::get_bits_be(&bytes, 0, 1), 1);
+        assert_eq!(super::get_bits_be(&bytes, 1, 1), 0);
+        assert_eq!(super::get_bits_be(&bytes, 0, 8), 0b1010_1100);
+        assert_eq!(super::get_bits_be(&bytes, 4, 8), 0b1100_0101);
+        assert_eq!(super::get_bits_be(&bytes, 0, 16), 0b1010_1100_0101_0011);
+        assert_eq!(super::get_bits_be(&bytes, 6, 4), 0b0001);
+
+        let bytes = 0x0123_4567_89ab_cdef_u64.to_be_bytes();
+        assert_eq!(super::get_bits_be(&bytes, 0, 64), 0x0123_4567_89ab_cdef);
+        assert_eq!(super::get_bits_be(&bytes, 4, 60), 0x0123_4567_89ab_cdef);
+        assert_eq!(super::get_bits_be(&bytes, 12, 24), 0x345678);
+
+        let bytes = [0xff; 9];
+        assert_eq!(super::get_bits_be(&bytes, 3, 64), u64::MAX);
+    }
+
+    #[test]
+    fn set_bits_be() {
+        let mut bytes = [0; 2];
+        super::set_bits_be(&mut bytes, 4, 8, 0xab);
+        assert_eq!(bytes, [0x0a, 0xb0]);
+        super::set_bits_be(&mut bytes, 0, 4, 0xff);
+        assert_eq!(bytes, [0xfa, 0xb0]);
+        super::set_bits_be(&mut bytes, 4, 8, 0);
+        assert_eq!(bytes, [0xf0, 0x00]);
+        super::set_bits_be(&mut bytes, 16, 0, 0xff);
+        assert_eq!(bytes, [0xf0, 0x00]);
+
+        let mut bytes = [0; 9];
+        super::set_bits_be(&mut bytes, 3, 64, u64::MAX);
+        assert_eq!(
+            bytes,
+            [0x1f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xe0]
+        );
+
+        let mut bytes = [0; 8];
+        super::set_bits_be(&mut bytes, 0, 64, 0x0123_4567_89ab_cdef);
+        assert_eq!(u64::from_be_bytes(bytes), 0x0123_4567_89ab_cdef);
+    }
+
+    #[test]
+    fn get_bits_le() {
+        let bytes = [0b1010_1100, 0b0101_0011];
+        assert_eq!(super::get_bits_le(&bytes, 0, 0), 0);
+        assert_eq!(super::get_bits_le(&bytes, 0, 1), 0);
+        assert_eq!(super::get_bits_le(&bytes, 2, 1), 1);
+        assert_eq!(super::get_bits_le(&bytes, 0, 8), 0b1010_1100);
+        assert_eq!(super::get_bits_le(&bytes, 4, 8), 0b0011_1010);
+        assert_eq!(super::get_bits_le(&bytes, 0, 16), 0b0101_0011_1010_1100);
+
+        let bytes = 0x0123_4567_89ab_cdef_u64.to_le_bytes();
+        assert_eq!(super::get_bits_le(&bytes, 0, 64), 0x0123_4567_89ab_cdef);
+        assert_eq!(super::get_bits_le(&bytes, 4, 60), 0x0012_3456_789a_bcde);
+        assert_eq!(super::get_bits_le(&bytes, 12, 24), 0x789abc);
+
+        let bytes = [0xff; 9];
+        assert_eq!(super::get_bits_le(&bytes, 3, 64), u64::MAX);
+    }
+
+    #[test]
+    fn set_bits_le() {
+        let mut bytes = [0; 2];
+        super::set_bits_le(&mut bytes, 4, 8, 0xab);
+        assert_eq!(bytes, [0xb0, 0x0a]);
+        super::set_bits_le(&mut bytes, 0, 4, 0xff);
+        assert_eq!(bytes, [0xbf, 0x0a]);
+        super::set_bits_le(&mut bytes, 4, 8, 0);
+        assert_eq!(bytes, [0x0f, 0x00]);
+
+        let mut bytes = [0; 9];
+        super::set_bits_le(&mut bytes, 3, 64, u64::MAX);
+        assert_eq!(
+            bytes,
+            [0xf8, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x07]
+        );
+
+        let mut bytes = [0; 8];
+        super::set_bits_le(&mut bytes, 0, 64, 0x0123_4567_89ab_cdef);
+        assert_eq!(u64::from_le_bytes(bytes), 0x0123_4567_89ab_cdef);
+    }
+
+    /// Checks that a field written at any offset can be read back and that
+    /// neighbouring bits are untouched.
+    #[test]
+    fn roundtrip() {
+        for bits in 0..=64 {
+            // `bits == 0` at offset 128 is not representable as u128 shift
+            for offset in 0..=(128 - bits).min(127) {
+                let value = 0xa5c3_5a3c_0ff0_f00f_u64 & create_mask_u64(bits);
+
+                let mut bytes = [0xff_u8; 16];
+                super::set_bits_be(&mut bytes, offset, bits, value);
+                assert_eq!(super::get_bits_be(&bytes, offset, bits), value);
+                let ones = u128::from_be_bytes(bytes).count_ones() as usize;
+                assert_eq!(ones, 128 - bits + value.count_ones() as usize);
+
+                let mut bytes = [0xff_u8; 16];
+                super::set_bits_le(&mut bytes, offset, bits, value);
+                assert_eq!(super::get_bits_le(&bytes, offset, bits), value);
+                assert_eq!(
+                    u128::from_le_bytes(bytes),
+                    crate::bitops_u128::set_bits_exact(
+                        u128::MAX,
+                        value as u128,
+                        bits as u128,
+                        offset as u128
+                    )
+                );
+            }
+        }
+    }
+
+    fn create_mask_u64(bits: usize) -> u64 {
+        crate::bitops_u64::create_mask(bits as u64)
+    }
+
+    #[test]
+    #[should_panic]
+    fn out_of_range() {
+        let _ = super::get_bits_be(&[0; 2], 9, 8);
+    }
+
+    #[test]
+    #[should_panic]
+    fn too_wide() {
+        let _ = super::get_bits_le(&[0; 16], 0, 65);
+    }
+}
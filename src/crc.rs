@@ -0,0 +1,331 @@
+//! Module providing a generic CRC engine for widths from 3 to 64 bits.
+//!
+//! [`Crc`] is parameterized like the "Rocksoft model" used by the common CRC
+//! catalogues, so that the parameters of a catalogued CRC can be used as they
+//! are. Input can be processed
+//!
+//! - bit by bit, including bit fields that are not byte aligned, e.g., the
+//!   15-bit CRC of CAN frames or the 5-bit CRC of USB tokens, and
+//! - byte by byte, using a lookup table that is generated at compile time.
+//!
+//! All functions are `const`. Common CRCs are available as type aliases, e.g.,
+//! [`Crc32`].
+
+use crate::bitops_u64;
+
+/// Generic CRC engine with a running register.
+///
+/// # Parameters
+///
+/// - `WIDTH`: Width of the CRC in bits (`3..=64`).
+/// - `POLY`: Generator polynomial without the leading (implicit) term, in
+///   normal (non-reflected) notation.
+/// - `INIT`: Initial register value, in normal (non-reflected) notation.
+/// - `REFIN`: Whether the bits of each input byte are processed LSB-first.
+/// - `REFOUT`: Whether the final register value is reflected.
+/// - `XOROUT`: Value that is XORed to the final (reflected) register value.
+///
+/// # Example
+/// ```rust
+/// use bit_ops::crc::{Crc, Crc32};
+///
+/// assert_eq!(Crc32::checksum(b"123456789"), 0xcbf4_3926);
+///
+/// // CRC-16/IBM-3740, also known as CRC-16/CCITT-FALSE
+/// type Crc16 = Crc<16, 0x1021, 0xffff, false, false, 0>;
+/// let mut crc = Crc16::new();
+/// crc.update_bytes(b"1234");
+/// crc.update_bytes(b"56789");
+/// assert_eq!(crc.finalize(), 0x29b1);
+///
+/// // Bit fields: the first bit of a field is its most significant bit for
+/// // `REFIN == false`.
+/// let mut crc = Crc16::new();
+/// crc.update_bits(0x31, 8);
+/// crc.update_bits(0x3233 >> 2, 14);
+/// crc.update_bits(0x3233 & 0b11, 2);
+/// crc.update_bytes(b"456789");
+/// assert_eq!(crc.finalize(), 0x29b1);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Crc<
+    const WIDTH: u32,
+    const POLY: u64,
+    const INIT: u64,
+    const REFIN: bool,
+    const REFOUT: bool,
+    const XOROUT: u64,
+> {
+    /// CRC register in normal notation, aligned to the MSB of the [`u64`].
+    register: u64,
+}
+
+impl<
+    const WIDTH: u32,
+    const POLY: u64,
+    const INIT: u64,
+    const REFIN: bool,
+    const REFOUT: bool,
+    const XOROUT: u64,
+> Crc<WIDTH, POLY, INIT, REFIN, REFOUT, XOROUT>
+{
+    /// Shift to align values of `WIDTH` bits to the MSB of a [`u64`].
+    const ALIGN: u32 = {
+        assert!(
+            WIDTH >= 3 && WIDTH <= 64,
+            "CRC width must be in range 3..=64"
+        );
+        let mask = bitops_u64::create_mask(WIDTH as u64);
+        assert!(POLY & !mask == 0, "polynomial has more bits than the CRC");
+        assert!(
+            INIT & !mask == 0,
+            "initial value has more bits than the CRC"
+        );
+        assert!(
+            XOROUT & !mask == 0,
+            "output XOR value has more bits than the CRC"
+        );
+        u64::BITS - WIDTH
+    };
+
+    /// Generator polynomial aligned to the MSB.
+    const POLY_ALIGNED: u64 = POLY << Self::ALIGN;
+
+    /// Lookup table for the byte-wise processing, indexed by the top byte of
+    /// the register XOR the next input byte.
+    const TABLE: [u64; 256] = {
+        let mut table = [0; 256];
+        let mut i = 0;
+        while i < table.len() {
+            let mut register = (i as u64) << 56;
+            let mut bit = 0;
+            while bit < 8 {
+                register = Self::shift(register, 0);
+                bit += 1;
+            }
+            table[i] = register;
+            i += 1;
+        }
+        table
+    };
+
+    /// Shifts a single input bit into the aligned register.
+    #[inline]
+    const fn shift(register: u64, bit: u64) -> u64 {
+        let feedback = (register >> 63) ^ bit;
+        let register = register << 1;
+        if feedback == 1 {
+            register ^ Self::POLY_ALIGNED
+        } else {
+            register
+        }
+    }
+
+    /// Creates a new engine with the register set to `INIT`.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            register: INIT << Self::ALIGN,
+        }
+    }
+
+    /// Processes the lowest `bits` bits of `value`, one bit at a time.
+    ///
+    /// The bits are processed starting with the most significant one, or
+    /// with the least significant one if `REFIN` is set. This matches the
+    /// bit order of [`Self::update_bytes`] when processing full bytes.
+    ///
+    /// # Parameters
+    ///
+    /// - `value`: Bit field to process.
+    /// - `bits`: Amount of bits of `value` that are relevant (`0..=64`).
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `bits > 64`.
+    pub const fn update_bits(&mut self, value: u64, bits: u64) {
+        assert!(bits <= 64, "a field must not be wider than 64 bits");
+        let mut i = 0;
+        while i < bits {
+            let bit = if REFIN { i } else { bits - 1 - i };
+            self.register = Self::shift(self.register, bitops_u64::get_bit(value, bit));
+            i += 1;
+        }
+    }
+
+    /// Processes `bytes` one bit at a time, without the lookup table.
+    ///
+    /// This is equivalent to [`Self::update_bytes`] but avoids the 2 KiB
+    /// lookup table, e.g., for code size reasons.
+    pub const fn update_bytes_bitwise(&mut self, bytes: &[u8]) {
+        let mut i = 0;
+        while i < bytes.len() {
+            self.update_bits(bytes[i] as u64, 8);
+            i += 1;
+        }
+    }
+
+    /// Processes `bytes` one byte at a time, using a lookup table.
+    pub const fn update_bytes(&mut self, bytes: &[u8]) {
+        let mut i = 0;
+        while i < bytes.len() {
+            let byte = if REFIN {
+                bytes[i].reverse_bits()
+            } else {
+                bytes[i]
+            };
+            let index = ((self.register >> 56) as u8 ^ byte) as usize;
+            self.register = (self.register << 8) ^ Self::TABLE[index];
+            i += 1;
+        }
+    }
+
+    /// Returns the CRC of all processed input.
+    ///
+    /// The engine can be updated further afterward.
+    #[must_use]
+    pub const fn finalize(&self) -> u64 {
+        let register = self.register >> Self::ALIGN;
+        let register = if REFOUT {
+            bitops_u64::reverse_low_bits(register, WIDTH as u64)
+        } else {
+            register
+        };
+        register ^ XOROUT
+    }
+
+    /// Returns the CRC of `bytes`.
+    #[must_use]
+    pub const fn checksum(bytes: &[u8]) -> u64 {
+        let mut crc = Self::new();
+        crc.update_bytes(bytes);
+        crc.finalize()
+    }
+
+    /// Returns the CRC of the lowest `bits` bits of `value`.
+    ///
+    /// See [`Self::update_bits`] for the bit order.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `bits > 64`.
+    #[must_use]
+    pub const fn checksum_bits(value: u64, bits: u64) -> u64 {
+        let mut crc = Self::new();
+        crc.update_bits(value, bits);
+        crc.finalize()
+    }
+}
+
+impl<
+    const WIDTH: u32,
+    const POLY: u64,
+    const INIT: u64,
+    const REFIN: bool,
+    const REFOUT: bool,
+    const XOROUT: u64,
+> Default for Crc<WIDTH, POLY, INIT, REFIN, REFOUT, XOROUT>
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// CRC-5/USB, used for USB token packets.
+pub type Crc5Usb = Crc<5, 0x05, 0x1f, true, true, 0x1f>;
+
+/// CRC-8/SMBUS.
+pub type Crc8 = Crc<8, 0x07, 0x00, false, false, 0x00>;
+
+/// CRC-15/CAN, used for CAN frames.
+pub type Crc15Can = Crc<15, 0x4599, 0x0000, false, false, 0x0000>;
+
+/// CRC-32/ISO-HDLC, used by Ethernet, zlib, and PNG.
+pub type Crc32 = Crc<32, 0x04c1_1db7, 0xffff_ffff, true, true, 0xffff_ffff>;
+
+/// CRC-64/XZ.
+pub type Crc64 =
+    Crc<64, 0x42f0_e1eb_a9ea_3693, 0xffff_ffff_ffff_ffff, true, true, 0xffff_ffff_ffff_ffff>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CHECK: &[u8] = b"123456789";
+
+    fn check<
+        const WIDTH: u32,
+        const POLY: u64,
+        const INIT: u64,
+        const REFIN: bool,
+        const REFOUT: bool,
+        const XOROUT: u64,
+    >(
+        expected: u64,
+    ) {
+        let mut bitwise = Crc::<WIDTH, POLY, INIT, REFIN, REFOUT, XOROUT>::new();
+        bitwise.update_bytes_bitwise(CHECK);
+        assert_eq!(bitwise.finalize(), expected, "WIDTH={WIDTH}");
+        assert_eq!(
+            Crc::<WIDTH, POLY, INIT, REFIN, REFOUT, XOROUT>::checksum(CHECK),
+            expected,
+            "WIDTH={WIDTH}"
+        );
+    }
+
+    #[test]
+    fn catalogue() {
+        // Check values of the catalogue of parametrised CRC algorithms.
+        check::<3, 0x3, 0x0, false, false, 0x7>(0x4);
+        check::<5, 0x05, 0x1f, true, true, 0x1f>(0x19);
+        check::<8, 0x07, 0x00, false, false, 0x00>(0xf4);
+        check::<15, 0x4599, 0x0000, false, false, 0x0000>(0x059e);
+        check::<16, 0x1021, 0xffff, false, false, 0x0000>(0x29b1);
+        check::<16, 0x8005, 0x0000, true, true, 0x0000>(0xbb3d);
+        check::<32, 0x04c1_1db7, 0xffff_ffff, true, true, 0xffff_ffff>(0xcbf4_3926);
+        check::<32, 0x1edc_6f41, 0xffff_ffff, true, true, 0xffff_ffff>(0xe306_9283);
+        check::<64, 0x42f0_e1eb_a9ea_3693, 0, false, false, 0>(0x6c40_df5f_0b49_7347);
+        check::<64, 0x42f0_e1eb_a9ea_3693, 0xffff_ffff_ffff_ffff, true, true, 0xffff_ffff_ffff_ffff>(
+            0x995d_c9bb_df19_39fa,
+        );
+    }
+
+    #[test]
+    fn bit_fields() {
+        // Splitting the input into arbitrary bit fields doesn't change the
+        // result. With `REFIN`, the fields are consumed LSB-first.
+        let value = u64::from_be_bytes(*b"23456789");
+        let mut crc = Crc15Can::new();
+        crc.update_bits(0x31, 8);
+        crc.update_bits(value >> 40, 24);
+        crc.update_bits(value >> 3, 37);
+        crc.update_bits(value & 0b111, 3);
+        assert_eq!(crc.finalize(), Crc15Can::checksum(CHECK));
+
+        let value = u64::from_le_bytes(*b"23456789");
+        let mut crc = Crc32::new();
+        crc.update_bits(0x31, 8);
+        crc.update_bits(value & 0x1f_ffff, 21);
+        crc.update_bits(value >> 21, 43);
+        assert_eq!(crc.finalize(), Crc32::checksum(CHECK));
+        assert_eq!(
+            Crc32::checksum_bits(value, 64),
+            Crc32::checksum(&CHECK[1..])
+        );
+
+        // USB token with address 0x15 and endpoint 0xe: the 11 bits are sent
+        // LSB-first, starting with the address. The CRC is sent MSB-first,
+        // i.e., `0b10111` in transmission order.
+        let crc = Crc5Usb::checksum_bits(0x15 | (0xe << 7), 11);
+        assert_eq!(crc, 0b11101);
+        assert_eq!(bitops_u64::reverse_low_bits(crc, 5), 0b10111);
+    }
+
+    #[test]
+    fn const_eval() {
+        const CRC: u64 = Crc8::checksum(CHECK);
+        assert_eq!(CRC, 0xf4);
+        assert_eq!(Crc8::default(), Crc8::new());
+        assert_eq!(Crc64::checksum(CHECK), 0x995d_c9bb_df19_39fa);
+    }
+}
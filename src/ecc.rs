@@ -0,0 +1,411 @@
+//! Module providing error-correcting code helpers based on parity checks.
+//!
+//! - Generic syndromes for a parity-check matrix, given as one mask per row:
+//!   Bit `k` of the syndrome is the parity of the word masked with row `k`.
+//!   See [`syndrome_u64`] and [`correct_u64`] (and the `u128` variants).
+//! - Hamming(7,4), correcting single-bit errors in 7-bit code words.
+//! - SECDED(72,64), as used by ECC memory: 64 data bits protected by 8 check
+//!   bits, correcting single-bit errors and detecting double-bit errors.
+//!
+//! All functions are `const` and built on `parity` of the Function API.
+
+use crate::{bitops_u8, bitops_u64};
+
+/// Implements the generic parity-check matrix functions for the specified
+/// primitive type.
+macro_rules! impl_parity_check {
+    ($primitive_ty:ty) => {
+        paste::paste! {
+            /// Computes the syndrome of `word` for the parity-check matrix
+            /// given by its rows.
+            ///
+            /// Bit `k` of the result is the parity of `word & rows[k]`. A
+            /// syndrome of `0` means that all parity checks are satisfied.
+            ///
+            /// # Parameters
+            ///
+            /// - `word`: Code word to check.
+            /// - `rows`: Rows of the parity-check matrix as masks (up to 64).
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use bit_ops::ecc::syndrome_", stringify!($primitive_ty), ";")]
+            ///
+            /// // Two parity groups
+            /// let rows = [0b0011, 0b1100];
+            #[doc = concat!("assert_eq!(syndrome_", stringify!($primitive_ty), "(0b1111, &rows), 0b00);")]
+            #[doc = concat!("assert_eq!(syndrome_", stringify!($primitive_ty), "(0b1101, &rows), 0b01);")]
+            /// ```
+            ///
+            /// # Panics
+            ///
+            /// This function panics if there are more than 64 rows.
+            #[must_use]
+            pub const fn [< syndrome_ $primitive_ty >](word: $primitive_ty, rows: &[$primitive_ty]) -> u64 {
+                assert!(rows.len() <= 64, "at most 64 rows are supported");
+                let mut syndrome = 0;
+                let mut k = 0;
+                while k < rows.len() {
+                    let parity = $crate::[< bitops_ $primitive_ty >]::parity(word & rows[k]);
+                    syndrome |= (parity as u64) << k;
+                    k += 1;
+                }
+                syndrome
+            }
+
+            /// Corrects a single-bit error in `word` given its syndrome.
+            ///
+            /// The erroneous bit is the one whose column in the parity-check
+            /// matrix equals the syndrome. Returns `None` if the syndrome is
+            /// non-zero and doesn't match any column, i.e., the error can't
+            /// be corrected.
+            ///
+            /// # Parameters
+            ///
+            /// - `word`: Code word to correct.
+            #[doc = concat!("- `syndrome`: Syndrome of `word` (see [`syndrome_", stringify!($primitive_ty), "`]).")]
+            /// - `rows`: Rows of the parity-check matrix as masks (up to 64).
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use bit_ops::ecc::{correct_", stringify!($primitive_ty), ", syndrome_", stringify!($primitive_ty), "};")]
+            ///
+            /// // Hamming(7,4) parity-check matrix
+            /// let rows = [0b101_0101, 0b110_0110, 0b111_1000];
+            /// let word = 0b101_0101 ^ (1 << 4);
+            #[doc = concat!("let syndrome = syndrome_", stringify!($primitive_ty), "(word, &rows);")]
+            #[doc = concat!("assert_eq!(correct_", stringify!($primitive_ty), "(word, syndrome, &rows), Some(0b101_0101));")]
+            /// ```
+            ///
+            /// # Panics
+            ///
+            /// This function panics if there are more than 64 rows.
+            #[must_use]
+            pub const fn [< correct_ $primitive_ty >](
+                word: $primitive_ty,
+                syndrome: u64,
+                rows: &[$primitive_ty],
+            ) -> Option<$primitive_ty> {
+                assert!(rows.len() <= 64, "at most 64 rows are supported");
+                if syndrome == 0 {
+                    return Some(word);
+                }
+                let mut bit = 0;
+                while bit < <$primitive_ty>::BITS {
+                    let mut column = 0;
+                    let mut k = 0;
+                    while k < rows.len() {
+                        column |= (((rows[k] >> bit) & 1) as u64) << k;
+                        k += 1;
+                    }
+                    if column == syndrome {
+                        return Some(word ^ (1 << bit));
+                    }
+                    bit += 1;
+                }
+                None
+            }
+        }
+    };
+}
+
+impl_parity_check!(u64);
+impl_parity_check!(u128);
+
+/// Rows of the parity-check matrix of Hamming(7,4).
+///
+/// Bit `i` of a code word is at position `i + 1` of the classic layout, where
+/// the check bits are at the positions `1`, `2`, and `4`. Thus, the syndrome
+/// of a single-bit error is its position. The rows are [`u64`] to pass them
+/// to [`syndrome_u64`] and [`correct_u64`] directly.
+const HAMMING74_ROWS: [u64; 3] = [0b101_0101, 0b110_0110, 0b111_1000];
+
+/// Bit indices of the data bits in a Hamming(7,4) code word.
+const HAMMING74_DATA_BITS: [u8; 4] = [2, 4, 5, 6];
+
+/// Returns the syndrome of a Hamming(7,4) code word.
+///
+/// A non-zero syndrome is the position (`1..=7`) of the erroneous bit, i.e.,
+/// bit `syndrome - 1` of the code word.
+#[must_use]
+pub const fn hamming74_syndrome(code: u8) -> u8 {
+    // Three rows yield a syndrome of at most three bits.
+    syndrome_u64(code as u64, &HAMMING74_ROWS) as u8
+}
+
+/// Encodes the lowest 4 bits of `data` as Hamming(7,4) code word.
+///
+/// # Example
+///
+/// ```rust
+/// use bit_ops::ecc::{hamming74_decode, hamming74_encode};
+///
+/// let code = hamming74_encode(0b1011);
+/// assert_eq!(code, 0b101_0101);
+/// // Any single-bit error is corrected.
+/// assert_eq!(hamming74_decode(code ^ 0b100), 0b1011);
+/// ```
+#[must_use]
+pub const fn hamming74_encode(data: u8) -> u8 {
+    let mut code = 0;
+    let mut i = 0;
+    while i < HAMMING74_DATA_BITS.len() {
+        code |= ((data >> i) & 1) << HAMMING74_DATA_BITS[i];
+        i += 1;
+    }
+    // The check bits are at positions 1, 2, and 4 (bits 0, 1, and 3), so the
+    // syndrome of the code word without them directly yields their values.
+    let syndrome = hamming74_syndrome(code);
+    code | (syndrome & 0b11) | ((syndrome & 0b100) << 1)
+}
+
+/// Corrects a single-bit error in a Hamming(7,4) code word.
+///
+/// Bit 7 of `code` is ignored and cleared.
+#[must_use]
+pub const fn hamming74_correct(code: u8) -> u8 {
+    let code = code & 0x7f;
+    match hamming74_syndrome(code) {
+        0 => code,
+        syndrome => code ^ (1 << (syndrome - 1)),
+    }
+}
+
+/// Returns the 4 data bits of a Hamming(7,4) code word after correcting a
+/// single-bit error.
+#[must_use]
+pub const fn hamming74_decode(code: u8) -> u8 {
+    let code = hamming74_correct(code);
+    let mut data = 0;
+    let mut i = 0;
+    while i < HAMMING74_DATA_BITS.len() {
+        data |= ((code >> HAMMING74_DATA_BITS[i]) & 1) << i;
+        i += 1;
+    }
+    data
+}
+
+/// Positions (`1..=71`) of the data bits in a SECDED(72,64) code word, i.e.,
+/// all positions that are not a power of two.
+const SECDED_DATA_POSITIONS: [u8; 64] = {
+    let mut positions = [0; 64];
+    let mut pos = 1_u8;
+    let mut i = 0;
+    while i < positions.len() {
+        if !pos.is_power_of_two() {
+            positions[i] = pos;
+            i += 1;
+        }
+        pos += 1;
+    }
+    positions
+};
+
+/// For each of the 7 Hamming check bits, the mask of data bits it covers.
+const SECDED_DATA_MASKS: [u64; 7] = {
+    let mut masks = [0; 7];
+    let mut i = 0;
+    while i < SECDED_DATA_POSITIONS.len() {
+        let mut k = 0;
+        while k < masks.len() {
+            if SECDED_DATA_POSITIONS[i] & (1 << k) != 0 {
+                masks[k] |= 1 << i;
+            }
+            k += 1;
+        }
+        i += 1;
+    }
+    masks
+};
+
+/// Returns the 8 check bits of SECDED(72,64) for `data`.
+///
+/// Bits `0..=6` are the Hamming check bits and bit `7` is the overall parity
+/// of the code word, so that the 72-bit code word has even parity.
+///
+/// # Example
+///
+/// ```rust
+/// use bit_ops::ecc::{secded_decode, secded_encode, SecdedStatus};
+///
+/// let data = 0x0123_4567_89ab_cdef;
+/// let check = secded_encode(data);
+/// assert_eq!(secded_decode(data, check), (data, SecdedStatus::NoError));
+/// assert_eq!(
+///     secded_decode(data ^ (1 << 42), check),
+///     (data, SecdedStatus::Corrected)
+/// );
+/// assert_eq!(
+///     secded_decode(data ^ 0b11, check).1,
+///     SecdedStatus::Uncorrectable
+/// );
+/// ```
+#[must_use]
+pub const fn secded_encode(data: u64) -> u8 {
+    let mut check = 0_u8;
+    let mut k = 0;
+    while k < SECDED_DATA_MASKS.len() {
+        check |= (bitops_u64::parity(data & SECDED_DATA_MASKS[k]) as u8) << k;
+        k += 1;
+    }
+    let overall = bitops_u64::parity(data) ^ bitops_u8::parity(check);
+    check | ((overall as u8) << 7)
+}
+
+/// Returns the syndrome of a SECDED(72,64) code word.
+///
+/// Bits `0..=6` are the Hamming syndrome, i.e., the position of a single-bit
+/// error, and bit `7` is set if the overall parity is violated.
+#[must_use]
+pub const fn secded_syndrome(data: u64, check: u8) -> u8 {
+    let expected = secded_encode(data) & 0x7f;
+    let overall = bitops_u64::parity(data) ^ bitops_u8::parity(check);
+    (expected ^ check) & 0x7f | ((overall as u8) << 7)
+}
+
+/// Result of the SECDED(72,64) error correction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SecdedStatus {
+    /// The code word has no error.
+    NoError,
+    /// A single-bit error was corrected.
+    Corrected,
+    /// An uncorrectable error, e.g., a double-bit error, was detected.
+    Uncorrectable,
+}
+
+/// Corrects a single-bit error in a SECDED(72,64) code word given its
+/// syndrome.
+///
+/// Returns the corrected data and check bits and the status. For
+/// uncorrectable errors, the code word is returned unchanged.
+///
+/// # Parameters
+///
+/// - `data`: Data bits of the code word.
+/// - `check`: Check bits of the code word.
+/// - `syndrome`: Syndrome of the code word (see [`secded_syndrome`]).
+#[must_use]
+pub const fn secded_correct(data: u64, check: u8, syndrome: u8) -> (u64, u8, SecdedStatus) {
+    let position = syndrome & 0x7f;
+    let parity_error = syndrome & 0x80 != 0;
+    match (position, parity_error) {
+        (0, false) => (data, check, SecdedStatus::NoError),
+        // Error in the overall parity bit
+        (0, true) => (data, check ^ 0x80, SecdedStatus::Corrected),
+        // Even amount of errors
+        (_, false) => (data, check, SecdedStatus::Uncorrectable),
+        // Error in a Hamming check bit
+        (position, true) if position.is_power_of_two() => (
+            data,
+            check ^ (1 << position.trailing_zeros()),
+            SecdedStatus::Corrected,
+        ),
+        (position, true) => {
+            // Position `p` holds data bit `p - floor(log2(p)) - 2`.
+            let bit = position as u32 - position.ilog2() - 2;
+            if bit < u64::BITS {
+                (data ^ (1 << bit), check, SecdedStatus::Corrected)
+            } else {
+                (data, check, SecdedStatus::Uncorrectable)
+            }
+        }
+    }
+}
+
+/// Returns the (corrected) data bits of a SECDED(72,64) code word and the
+/// status of the correction.
+///
+/// For uncorrectable errors, the data is returned unchanged.
+#[must_use]
+pub const fn secded_decode(data: u64, check: u8) -> (u64, SecdedStatus) {
+    let (data, _, status) = secded_correct(data, check, secded_syndrome(data, check));
+    (data, status)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hamming74() {
+        for data in 0..16 {
+            let code = hamming74_encode(data);
+            assert_eq!(hamming74_syndrome(code), 0);
+            assert_eq!(hamming74_decode(code), data);
+            for bit in 0..7 {
+                let corrupted = code ^ (1 << bit);
+                assert_eq!(hamming74_syndrome(corrupted), bit + 1);
+                assert_eq!(hamming74_correct(corrupted), code);
+                assert_eq!(hamming74_decode(corrupted), data);
+
+                // Same result with the generic functions
+                let syndrome = syndrome_u64(u64::from(corrupted), &HAMMING74_ROWS);
+                assert_eq!(syndrome, u64::from(bit) + 1);
+                assert_eq!(
+                    correct_u64(u64::from(corrupted), syndrome, &HAMMING74_ROWS),
+                    Some(u64::from(code))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn generic() {
+        let rows = [0b0011_u128 << 100, 0b0110 << 100];
+        assert_eq!(syndrome_u128(1 << 100, &rows), 0b01);
+        assert_eq!(syndrome_u128(1 << 101, &rows), 0b11);
+        assert_eq!(correct_u128(1 << 101, 0b11, &rows), Some(0));
+        // No column matches.
+        assert_eq!(correct_u64(0, 0b100, &[1, 2]), None);
+        assert_eq!(correct_u64(42, 0, &[1, 2]), Some(42));
+    }
+
+    #[test]
+    fn secded() {
+        for data in [0, u64::MAX, 0x0123_4567_89ab_cdef, 1 << 63, 0xdead_beef] {
+            let check = secded_encode(data);
+            assert_eq!(secded_syndrome(data, check), 0);
+            assert_eq!(secded_decode(data, check), (data, SecdedStatus::NoError));
+
+            // All single-bit errors are corrected.
+            for bit in 0..64 {
+                let corrupted = data ^ (1 << bit);
+                let syndrome = secded_syndrome(corrupted, check);
+                assert_eq!(
+                    secded_correct(corrupted, check, syndrome),
+                    (data, check, SecdedStatus::Corrected)
+                );
+            }
+            for bit in 0..8 {
+                let corrupted = check ^ (1 << bit);
+                let syndrome = secded_syndrome(data, corrupted);
+                assert_eq!(
+                    secded_correct(data, corrupted, syndrome),
+                    (data, check, SecdedStatus::Corrected)
+                );
+            }
+
+            // All double-bit errors are detected.
+            for a in 0..72 {
+                for b in (a + 1)..72 {
+                    let (mut data, mut check) = (data, check);
+                    for bit in [a, b] {
+                        if bit < 64 {
+                            data ^= 1 << bit;
+                        } else {
+                            check ^= 1 << (bit - 64);
+                        }
+                    }
+                    assert_eq!(
+                        secded_decode(data, check).1,
+                        SecdedStatus::Uncorrectable,
+                        "bits {a} and {b}"
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,478 @@
+//! Various bit manipulation operations for wide integers that exceed
+//! [`u128`], such as 256-bit or 512-bit registers.
+//!
+//! A wide integer is represented as `[u64; N]` in little-endian word order,
+//! i.e., bit `i` of the wide integer is bit `i % 64` of word `i / 64`. All
+//! functions are generic over `N` and `const`.
+//!
+//! Bit positions start at `0` and are given as [`usize`]. The highest bit
+//! position thus is `N * 64 - 1`. Fields are limited to `64` bits but may
+//! straddle word boundaries.
+//!
+//! All functions are non-mutating but produce a new value.
+//!
+//! # Example
+//!
+//! ```rust
+//! use bit_ops::bitops_wide;
+//!
+//! // AVX-512 mask with 512 bits
+//! let mask = bitops_wide::set_bit([0_u64; 8], 511);
+//! let mask = bitops_wide::set_bits_exact(mask, 0xabc, 12, 60);
+//! assert_eq!(bitops_wide::get_bits(mask, 12, 60), 0xabc);
+//! assert_eq!(mask[0], 0xc << 60);
+//! assert_eq!(mask[1], 0xab);
+//! assert_eq!(bitops_wide::highest_bit(mask), Some(511));
+//! assert_eq!(bitops_wide::lowest_bit(mask), Some(62));
+//! ```
+
+use crate::bitops_u64;
+
+/// Amount of bits of a single word.
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// Maximum width of a field in [`get_bits`] and [`set_bits_exact`].
+const MAX_FIELD_BITS: usize = WORD_BITS;
+
+#[track_caller]
+const fn assert_in_range<const N: usize>(n: usize, inclusive: bool) {
+    if inclusive {
+        assert!(
+            n <= N * WORD_BITS,
+            "bit position starts at 0 and should be less than or equal to `bitcount(type)`"
+        );
+    } else {
+        assert!(
+            n < N * WORD_BITS,
+            "bit position starts at 0 and should be less than `bitcount(type)`"
+        );
+    }
+}
+
+#[track_caller]
+const fn assert_field_bits(bits: usize) {
+    assert!(
+        bits <= MAX_FIELD_BITS,
+        "a field must not be wider than 64 bits"
+    );
+}
+
+/// Asserts that the field of `bits` bits at `shift` is within the range of
+/// the wide integer, without overflowing on huge values.
+#[track_caller]
+const fn assert_field_in_range<const N: usize>(bits: usize, shift: usize) {
+    assert_field_bits(bits);
+    match shift.checked_add(bits) {
+        Some(end) => assert_in_range::<N>(end, true),
+        None => assert_in_range::<N>(usize::MAX, true),
+    }
+}
+
+/// Sets the given bit to `1`.
+///
+/// # Parameters
+///
+/// - `base`: Base value to alter.
+/// - `bit`: Bit to set, starting at position `0`.
+///
+/// # Example
+///
+/// ```rust
+/// use bit_ops::bitops_wide::set_bit;
+///
+/// assert_eq!(set_bit([0; 4], 0), [1, 0, 0, 0]);
+/// assert_eq!(set_bit([0; 4], 255), [0, 0, 0, 1 << 63]);
+/// ```
+///
+/// # Panics
+/// This function panics for bit positions that are outside the range of
+/// the underlying type.
+#[must_use]
+#[inline]
+pub const fn set_bit<const N: usize>(base: [u64; N], bit: usize) -> [u64; N] {
+    assert_in_range::<N>(bit, false);
+    let mut base = base;
+    let word = bit / WORD_BITS;
+    base[word] = bitops_u64::set_bit(base[word], (bit % WORD_BITS) as u64);
+    base
+}
+
+/// Clears the given bit by setting it to `0`.
+///
+/// # Parameters
+///
+/// - `base`: Base value to alter.
+/// - `bit`: Bit to clear, starting at position `0`.
+///
+/// # Example
+///
+/// ```rust
+/// use bit_ops::bitops_wide::clear_bit;
+///
+/// assert_eq!(clear_bit([u64::MAX; 2], 64), [u64::MAX, u64::MAX - 1]);
+/// ```
+///
+/// # Panics
+/// This function panics for bit positions that are outside the range of
+/// the underlying type.
+#[must_use]
+#[inline]
+pub const fn clear_bit<const N: usize>(base: [u64; N], bit: usize) -> [u64; N] {
+    assert_in_range::<N>(bit, false);
+    let mut base = base;
+    let word = bit / WORD_BITS;
+    base[word] = bitops_u64::clear_bit(base[word], (bit % WORD_BITS) as u64);
+    base
+}
+
+/// Returns whether the given bit is set.
+///
+/// # Parameters
+///
+/// - `base`: Base value to check.
+/// - `bit`: Bit to check, starting at position `0`.
+///
+/// # Example
+///
+/// ```rust
+/// use bit_ops::bitops_wide::is_set;
+///
+/// assert!(is_set([0, 0b10], 65));
+/// assert!(!is_set([0, 0b10], 64));
+/// ```
+///
+/// # Panics
+/// This function panics for bit positions that are outside the range of
+/// the underlying type.
+#[must_use]
+#[inline]
+pub const fn is_set<const N: usize>(base: [u64; N], bit: usize) -> bool {
+    assert_in_range::<N>(bit, false);
+    bitops_u64::is_set(base[bit / WORD_BITS], (bit % WORD_BITS) as u64)
+}
+
+/// Like [`bitops_u64::set_bits_exact`]: Replaces the specified contiguous
+/// bits with the lowest `value_bits` bits of `value`.
+///
+/// The field may straddle a word boundary.
+///
+/// # Parameters
+///
+/// - `base`: Base value to alter.
+/// - `value`: New value/bits to be set in `base`.
+/// - `value_bits`: Amount of bits of `value` that are relevant (`0..=64`).
+/// - `value_shift`: Position of `value` inside `base`, starting from the
+///   right/LSB (`0`).
+///
+/// # Example
+///
+/// ```rust
+/// use bit_ops::bitops_wide::set_bits_exact;
+///
+/// let raw = set_bits_exact([u64::MAX; 2], 0, 8, 60);
+/// assert_eq!(raw, [0x0fff_ffff_ffff_ffff, 0xffff_ffff_ffff_fff0]);
+/// ```
+///
+/// # Panics
+///
+/// This function panics if `value_bits > 64` or if the field exceeds the
+/// range of the underlying type.
+#[must_use]
+#[inline]
+pub const fn set_bits_exact<const N: usize>(
+    base: [u64; N],
+    value: u64,
+    value_bits: usize,
+    value_shift: usize,
+) -> [u64; N] {
+    assert_field_in_range::<N>(value_bits, value_shift);
+    let mut base = base;
+    if value_bits == 0 {
+        return base;
+    }
+    let word = value_shift / WORD_BITS;
+    let offset = value_shift % WORD_BITS;
+    let low_bits = if WORD_BITS - offset < value_bits {
+        WORD_BITS - offset
+    } else {
+        value_bits
+    };
+    base[word] = bitops_u64::set_bits_exact(base[word], value, low_bits as u64, offset as u64);
+    if low_bits < value_bits {
+        let high_bits = (value_bits - low_bits) as u64;
+        base[word + 1] =
+            bitops_u64::set_bits_exact(base[word + 1], value >> low_bits, high_bits, 0);
+    }
+    base
+}
+
+/// Returns the requested contiguous bits as new integer.
+///
+/// The field may straddle a word boundary.
+///
+/// # Parameters
+///
+/// - `base`: Base value to get a specific set of bits from.
+/// - `value_bits`: Amount of bits of `base` that are relevant (`0..=64`).
+/// - `value_shift`: Position of the bits inside `base`, starting from the
+///   right/LSB (`0`).
+///
+/// # Example
+///
+/// ```rust
+/// use bit_ops::bitops_wide::get_bits;
+///
+/// let raw = [0xf000_0000_0000_0000, 0xa];
+/// assert_eq!(get_bits(raw, 8, 60), 0xaf);
+/// assert_eq!(get_bits(raw, 64, 64), 0xa);
+/// ```
+///
+/// # Panics
+///
+/// This function panics if `value_bits > 64` or if the field exceeds the
+/// range of the underlying type, just like [`set_bits_exact`].
+#[must_use]
+#[inline]
+pub const fn get_bits<const N: usize>(
+    base: [u64; N],
+    value_bits: usize,
+    value_shift: usize,
+) -> u64 {
+    assert_field_in_range::<N>(value_bits, value_shift);
+    let word = value_shift / WORD_BITS;
+    let offset = value_shift % WORD_BITS;
+    if word == N {
+        return 0;
+    }
+    let mut value = base[word] >> offset;
+    if offset != 0 && word + 1 < N {
+        value |= base[word + 1] << (WORD_BITS - offset);
+    }
+    value & bitops_u64::create_mask(value_bits as u64)
+}
+
+/// Returns the highest bit that is set, if any.
+///
+/// # Parameters
+///
+/// - `base`: Base value to get highest bit from.
+///
+/// # Example
+///
+/// ```rust
+/// use bit_ops::bitops_wide::highest_bit;
+///
+/// assert_eq!(highest_bit([0; 4]), None);
+/// assert_eq!(highest_bit([1, 0, 0b10, 0]), Some(129));
+/// ```
+#[must_use]
+#[inline]
+pub const fn highest_bit<const N: usize>(base: [u64; N]) -> Option<usize> {
+    let mut word = N;
+    while word > 0 {
+        word -= 1;
+        if let Some(bit) = bitops_u64::highest_bit(base[word]) {
+            return Some(word * WORD_BITS + bit as usize);
+        }
+    }
+    None
+}
+
+/// Returns the lowest bit that is set, if any.
+///
+/// # Parameters
+///
+/// - `base`: Base value to get lowest bit from.
+///
+/// # Example
+///
+/// ```rust
+/// use bit_ops::bitops_wide::lowest_bit;
+///
+/// assert_eq!(lowest_bit([0; 4]), None);
+/// assert_eq!(lowest_bit([0, 0, 0b10, 1]), Some(129));
+/// ```
+#[must_use]
+#[inline]
+pub const fn lowest_bit<const N: usize>(base: [u64; N]) -> Option<usize> {
+    let mut word = 0;
+    while word < N {
+        if let Some(bit) = bitops_u64::lowest_bit(base[word]) {
+            return Some(word * WORD_BITS + bit as usize);
+        }
+        word += 1;
+    }
+    None
+}
+
+/// Creates a bitmask (`1`s) with the given amount of contiguous bits.
+///
+/// # Parameters
+///
+/// - `bits`: Amount of contiguous bits.
+///
+/// # Example
+///
+/// ```rust
+/// use bit_ops::bitops_wide::create_mask;
+///
+/// assert_eq!(create_mask::<2>(0), [0, 0]);
+/// assert_eq!(create_mask::<2>(68), [u64::MAX, 0xf]);
+/// assert_eq!(create_mask::<2>(128), [u64::MAX; 2]);
+/// ```
+///
+/// # Panics
+///
+/// This function panics for bit positions that are outside the range
+/// of the underlying type.
+#[must_use]
+#[inline]
+pub const fn create_mask<const N: usize>(bits: usize) -> [u64; N] {
+    assert_in_range::<N>(bits, true);
+    let mut mask = [0; N];
+    let mut word = 0;
+    while word < N {
+        let start = word * WORD_BITS;
+        if bits >= start + WORD_BITS {
+            mask[word] = u64::MAX;
+        } else if bits > start {
+            mask[word] = bitops_u64::create_mask((bits - start) as u64);
+        }
+        word += 1;
+    }
+    mask
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_bit() {
+        assert_eq!(super::set_bit([0; 1], 0), [1]);
+        assert_eq!(super::set_bit([0; 3], 64), [0, 1, 0]);
+        assert_eq!(super::set_bit([0; 3], 191), [0, 0, 1 << 63]);
+        assert_eq!(super::set_bit([1, 0], 0), [1, 0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn set_bit_out_of_range() {
+        let _ = super::set_bit([0; 2], 128);
+    }
+
+    #[test]
+    fn clear_bit() {
+        assert_eq!(super::clear_bit([1, 1], 0), [0, 1]);
+        assert_eq!(super::clear_bit([1, 1], 64), [1, 0]);
+        assert_eq!(super::clear_bit([1, 1], 65), [1, 1]);
+    }
+
+    #[test]
+    fn is_set() {
+        assert!(super::is_set([0, 0, 0, 1 << 63], 255));
+        assert!(!super::is_set([u64::MAX, 0, u64::MAX, 0], 64));
+    }
+
+    #[test]
+    fn set_bits_exact() {
+        assert_eq!(super::set_bits_exact([0; 2], 0xff, 0, 10), [0; 2]);
+        assert_eq!(super::set_bits_exact([0; 2], 0xff, 8, 0), [0xff, 0]);
+        assert_eq!(super::set_bits_exact([0; 2], 0xff, 8, 120), [0, 0xff << 56]);
+        assert_eq!(super::set_bits_exact([0; 2], 0xff, 8, 60), [0xf << 60, 0xf]);
+        assert_eq!(
+            super::set_bits_exact([0; 2], u64::MAX, 64, 64),
+            [0, u64::MAX]
+        );
+        assert_eq!(
+            super::set_bits_exact([u64::MAX; 3], 0, 64, 96),
+            [u64::MAX, 0xffff_ffff, 0xffff_ffff_0000_0000]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn set_bits_exact_out_of_range() {
+        let _ = super::set_bits_exact([0; 2], 0xff, 8, 121);
+    }
+
+    #[test]
+    fn get_bits() {
+        let raw = [0x0123_4567_89ab_cdef, 0xfedc_ba98_7654_3210];
+        assert_eq!(super::get_bits(raw, 0, 0), 0);
+        assert_eq!(super::get_bits(raw, 0, 128), 0);
+        assert_eq!(super::get_bits(raw, 64, 0), 0x0123_4567_89ab_cdef);
+        assert_eq!(super::get_bits(raw, 64, 64), 0xfedc_ba98_7654_3210);
+        assert_eq!(super::get_bits(raw, 16, 56), 0x1001);
+        assert_eq!(super::get_bits(raw, 64, 32), 0x7654_3210_0123_4567);
+        assert_eq!(super::get_bits(raw, 8, 120), 0xfe);
+    }
+
+    #[test]
+    #[should_panic(expected = "bit position starts at 0")]
+    fn get_bits_out_of_range() {
+        let _ = super::get_bits([0; 2], 16, 120);
+    }
+
+    #[test]
+    #[should_panic(expected = "bit position starts at 0")]
+    fn huge_shift() {
+        let _ = super::set_bits_exact([0; 2], 0xff, 8, usize::MAX);
+    }
+
+    /// Compares the functions with the ones of [`bitops_u128`] for two words.
+    #[test]
+    fn matches_u128() {
+        use crate::bitops_u128;
+
+        let to_wide = |value: u128| [value as u64, (value >> 64) as u64];
+        let base = 0x0123_4567_89ab_cdef_fedc_ba98_7654_3210_u128;
+        for bits in 0..=64 {
+            // `bits == 0` at shift 128 is not representable as u128 shift
+            for shift in 0..=(128 - bits).min(127) {
+                let value = 0xa5a5_5a5a_c3c3_3c3c;
+                assert_eq!(
+                    super::set_bits_exact(to_wide(base), value, bits, shift),
+                    to_wide(bitops_u128::set_bits_exact(
+                        base,
+                        value as u128,
+                        bits as u128,
+                        shift as u128
+                    ))
+                );
+                assert_eq!(
+                    super::get_bits(to_wide(base), bits, shift) as u128,
+                    bitops_u128::get_bits(base, bits as u128, shift as u128)
+                );
+            }
+        }
+        for bits in 0..=128 {
+            assert_eq!(
+                super::create_mask::<2>(bits),
+                to_wide(bitops_u128::create_mask(bits as u128))
+            );
+        }
+    }
+
+    #[test]
+    fn highest_lowest_bit() {
+        assert_eq!(highest_bit::<0>([]), None);
+        assert_eq!(lowest_bit::<0>([]), None);
+        assert_eq!(highest_bit([0; 8]), None);
+        assert_eq!(lowest_bit([0; 8]), None);
+        assert_eq!(highest_bit([u64::MAX; 8]), Some(511));
+        assert_eq!(lowest_bit([u64::MAX; 8]), Some(0));
+        assert_eq!(highest_bit([0, 1, 1 << 5, 0]), Some(133));
+        assert_eq!(lowest_bit([0, 1 << 7, 1, 0]), Some(71));
+    }
+
+    #[test]
+    fn create_mask() {
+        assert_eq!(super::create_mask::<0>(0), []);
+        assert_eq!(super::create_mask::<3>(0), [0; 3]);
+        assert_eq!(super::create_mask::<3>(1), [1, 0, 0]);
+        assert_eq!(super::create_mask::<3>(64), [u64::MAX, 0, 0]);
+        assert_eq!(super::create_mask::<3>(65), [u64::MAX, 1, 0]);
+        assert_eq!(super::create_mask::<3>(192), [u64::MAX; 3]);
+    }
+}
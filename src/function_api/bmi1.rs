@@ -0,0 +1,53 @@
+//! Helpers around the `blsi`, `blsr`, and `blsmsk` instructions of the x86
+//! `bmi1` extension, used by the non-`const` `*_bmi1` variants of the Function
+//! API.
+//!
+//! The helpers operate on up to 128 bits so that they can be used with all
+//! widths. Narrower values must be zero-extended.
+
+use core::arch::x86_64::{_blsi_u64, _blsmsk_u64, _blsr_u64};
+
+/// `blsi` on up to 128 bits.
+#[inline]
+#[allow(unused_unsafe)]
+pub(super) fn blsi(src: u128) -> u128 {
+    let lo = src as u64;
+    let hi = (src >> 64) as u64;
+    if lo != 0 {
+        // SAFETY: The `bmi1` target feature is enabled.
+        unsafe { _blsi_u64(lo) as u128 }
+    } else {
+        // SAFETY: The `bmi1` target feature is enabled.
+        unsafe { (_blsi_u64(hi) as u128) << 64 }
+    }
+}
+
+/// `blsr` on up to 128 bits.
+#[inline]
+#[allow(unused_unsafe)]
+pub(super) fn blsr(src: u128) -> u128 {
+    let lo = src as u64;
+    let hi = (src >> 64) as u64;
+    if lo != 0 {
+        // SAFETY: The `bmi1` target feature is enabled.
+        unsafe { ((hi as u128) << 64) | _blsr_u64(lo) as u128 }
+    } else {
+        // SAFETY: The `bmi1` target feature is enabled.
+        unsafe { (_blsr_u64(hi) as u128) << 64 }
+    }
+}
+
+/// `blsmsk` on up to 128 bits.
+#[inline]
+#[allow(unused_unsafe)]
+pub(super) fn blsmsk(src: u128) -> u128 {
+    let lo = src as u64;
+    let hi = (src >> 64) as u64;
+    if lo != 0 {
+        // SAFETY: The `bmi1` target feature is enabled.
+        unsafe { _blsmsk_u64(lo) as u128 }
+    } else {
+        // SAFETY: The `bmi1` target feature is enabled.
+        unsafe { ((_blsmsk_u64(hi) as u128) << 64) | u64::MAX as u128 }
+    }
+}
@@ -0,0 +1,40 @@
+//! Helpers around the `pdep` and `pext` instructions of the x86 `bmi2`
+//! extension, used by the non-`const` `*_bmi2` variants of the Function API.
+//!
+//! The helpers operate on up to 128 bits so that they can be used with all
+//! widths.
+
+/// `pdep` on up to 128 bits.
+#[inline]
+#[allow(unused_unsafe)]
+pub(super) fn pdep(src: u128, mask: u128) -> u128 {
+    use core::arch::x86_64::_pdep_u64;
+    let mask_lo = mask as u64;
+    let mask_hi = (mask >> 64) as u64;
+    // SAFETY: The `bmi2` target feature is enabled.
+    let lo = unsafe { _pdep_u64(src as u64, mask_lo) } as u128;
+    if mask_hi == 0 {
+        return lo;
+    }
+    let src_hi = (src >> mask_lo.count_ones()) as u64;
+    // SAFETY: The `bmi2` target feature is enabled.
+    let hi = unsafe { _pdep_u64(src_hi, mask_hi) } as u128;
+    lo | (hi << 64)
+}
+
+/// `pext` on up to 128 bits.
+#[inline]
+#[allow(unused_unsafe)]
+pub(super) fn pext(src: u128, mask: u128) -> u128 {
+    use core::arch::x86_64::_pext_u64;
+    let mask_lo = mask as u64;
+    let mask_hi = (mask >> 64) as u64;
+    // SAFETY: The `bmi2` target feature is enabled.
+    let lo = unsafe { _pext_u64(src as u64, mask_lo) } as u128;
+    if mask_hi == 0 {
+        return lo;
+    }
+    // SAFETY: The `bmi2` target feature is enabled.
+    let hi = unsafe { _pext_u64((src >> 64) as u64, mask_hi) } as u128;
+    lo | (hi << mask_lo.count_ones())
+}
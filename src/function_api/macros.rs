@@ -21,12 +21,60 @@ LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
 OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 SOFTWARE.
 */
+/// Expands to an example bit pattern string for the documentation of
+/// `parse_pattern` and friends: the pattern `xx01_..1x` for the highest byte,
+/// followed by don't-care bits for all remaining bits of the type.
+macro_rules! example_pattern {
+    (u8) => {
+        "xx01_..1x"
+    };
+    (u16) => {
+        "xx01_..1x_xxxx_xxxx"
+    };
+    (u32) => {
+        "xx01_..1x_xxxx_xxxx_xxxx_xxxx_xxxx_xxxx"
+    };
+    (u64) => {
+        "xx01_..1x_xxxx_xxxx_xxxx_xxxx_xxxx_xxxx_xxxx_xxxx_xxxx_xxxx_xxxx_xxxx_xxxx_xxxx"
+    };
+    (u128) => {
+        "xx01_..1x_xxxx_xxxx_xxxx_xxxx_xxxx_xxxx_xxxx_xxxx_xxxx_xxxx_xxxx_xxxx_xxxx_xxxx_xxxx_xxxx_xxxx_xxxx_xxxx_xxxx_xxxx_xxxx_xxxx_xxxx_xxxx_xxxx_xxxx_xxxx_xxxx_xxxx"
+    };
+    (usize) => {
+        example_pattern_usize!()
+    };
+}
+
+/// [`example_pattern`] for [`usize`] on 16-bit targets.
+#[cfg(target_pointer_width = "16")]
+macro_rules! example_pattern_usize {
+    () => {
+        example_pattern!(u16)
+    };
+}
+
+/// [`example_pattern`] for [`usize`] on 32-bit targets.
+#[cfg(target_pointer_width = "32")]
+macro_rules! example_pattern_usize {
+    () => {
+        example_pattern!(u32)
+    };
+}
+
+/// [`example_pattern`] for [`usize`] on 64-bit targets.
+#[cfg(target_pointer_width = "64")]
+macro_rules! example_pattern_usize {
+    () => {
+        example_pattern!(u64)
+    };
+}
+
 /// Implements the relevant bit operations for the specified primitive type.
 ///
 /// Note that the bit positions start at `0`. The highest `bit` position thus
 /// is `BITS - 1`.
 macro_rules! impl_bit_ops {
-    ($primitive_ty:ty) => {
+    ($primitive_ty:ident) => {
         /// Amount of bits for that type.
         const BIT_COUNT: $primitive_ty = <$primitive_ty>::BITS as $primitive_ty;
 
@@ -626,19 +674,1707 @@ macro_rules! impl_bit_ops {
                 }
             }
         }
-    };
-}
 
-/// Implements the module wrapping the corresponding [`impl_bit_ops`] code.
-macro_rules! impl_mod {
-    ($primitive_ty:ty) => {
-        paste::paste! {
-            /// Various bit manipulation operations for the primitive type
-            #[doc = concat!("[`", stringify!($primitive_ty), "`].")]
-            ///
-            /// All functions are non-mutating but produce a new value.
-            pub mod [< bitops _ $primitive_ty >]  {
-                impl_bit_ops!($primitive_ty);
+        /// Reverses the lowest `n` bits of `base`.
+        ///
+        /// The result only contains the reversed `n` bits; all other bits are
+        /// `0`. This is the typical "reflect" operation of CRC algorithms and
+        /// the index mapping of the FFT bit-reverse permutation.
+        ///
+        /// # Parameters
+        ///
+        /// - `base`: Base value to get the bits from.
+        /// - `n`: Amount of low bits to reverse.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        #[doc = concat!("use bit_ops::bitops_", stringify!($primitive_ty), "::reverse_low_bits;")]
+        ///
+        /// assert_eq!(reverse_low_bits(0b0001, 4), 0b1000);
+        /// assert_eq!(reverse_low_bits(0b1101, 3), 0b101);
+        /// assert_eq!(reverse_low_bits(0b0110, 4), 0b0110);
+        /// assert_eq!(reverse_low_bits(0b1111, 0), 0);
+        /// ```
+        ///
+        /// # Panics
+        ///
+        /// This function panics for bit positions that are outside the range
+        /// of the underlying type.
+        #[must_use]
+        #[inline]
+        pub const fn reverse_low_bits(base: $primitive_ty, n: $primitive_ty) -> $primitive_ty {
+            assert_in_range(n, true);
+            if n == 0 {
+                0
+            } else {
+                base.reverse_bits() >> (BIT_COUNT - n)
+            }
+        }
+
+        /// Reverses the order of the specified contiguous bits while leaving
+        /// all other bits of `base` unchanged.
+        ///
+        /// # Parameters
+        ///
+        /// - `base`: Base value to alter.
+        /// - `bits`: Amount of bits of `base` that are relevant.
+        /// - `shift`: Relevant position of bits inside `base`, starting from
+        ///            the right/LSB (`0`).
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        #[doc = concat!("use bit_ops::bitops_", stringify!($primitive_ty), "::reverse_bits_in_field;")]
+        ///
+        /// assert_eq!(reverse_bits_in_field(0b1_0011_1, 4, 1), 0b1_1100_1);
+        /// assert_eq!(reverse_bits_in_field(0b1100_0001, 4, 0), 0b1100_1000);
+        /// ```
+        ///
+        /// # Panics
+        ///
+        /// This function panics for overflowing shifts and bit positions that
+        /// are outside the range of the underlying type.
+        #[must_use]
+        #[inline]
+        pub const fn reverse_bits_in_field(
+            base: $primitive_ty,
+            bits: $primitive_ty,
+            shift: $primitive_ty,
+        ) -> $primitive_ty {
+            let field = reverse_low_bits(get_bits(base, bits, shift), bits);
+            set_bits_exact(base, field, bits, shift)
+        }
+
+        /// Creates an iterator over the bit-reverse permutation of the indices
+        /// `0..2^order`.
+        ///
+        /// For each index `i` in ascending order, the iterator emits
+        /// `reverse_low_bits(i, order)`. This is the index order of the
+        /// in-place (radix-2) FFT.
+        ///
+        /// # Parameters
+        ///
+        /// - `order`: Amount of bits of each index.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        #[doc = concat!("use bit_ops::bitops_", stringify!($primitive_ty), "::bit_reverse_permutation;")]
+        ///
+        /// let indices = bit_reverse_permutation(3).collect::<Vec<_>>();
+        /// assert_eq!(&indices, &[0, 4, 2, 6, 1, 5, 3, 7]);
+        /// ```
+        ///
+        /// # Panics
+        ///
+        /// This function panics for bit positions that are outside the range
+        /// of the underlying type.
+        #[must_use]
+        #[inline]
+        pub const fn bit_reverse_permutation(
+            order: $primitive_ty,
+        ) -> $crate::BitReversePermutationIter<$primitive_ty> {
+            $crate::BitReversePermutationIter::<$primitive_ty>::new(order)
+        }
+
+        /// Converts a binary value to its (reflected binary) Gray code.
+        ///
+        /// Consecutive values differ in exactly one bit in Gray code.
+        ///
+        /// # Parameters
+        ///
+        /// - `base`: Binary value to convert.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        #[doc = concat!("use bit_ops::bitops_", stringify!($primitive_ty), "::to_gray;")]
+        ///
+        /// assert_eq!(to_gray(0), 0b000);
+        /// assert_eq!(to_gray(1), 0b001);
+        /// assert_eq!(to_gray(2), 0b011);
+        /// assert_eq!(to_gray(3), 0b010);
+        /// assert_eq!(to_gray(4), 0b110);
+        /// ```
+        #[must_use]
+        #[inline]
+        pub const fn to_gray(base: $primitive_ty) -> $primitive_ty {
+            base ^ (base >> 1)
+        }
+
+        /// Converts a (reflected binary) Gray code back to its binary value.
+        ///
+        /// This is the inverse of [`to_gray`].
+        ///
+        /// # Parameters
+        ///
+        /// - `base`: Gray code to convert.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        #[doc = concat!("use bit_ops::bitops_", stringify!($primitive_ty), "::{from_gray, to_gray};")]
+        ///
+        /// assert_eq!(from_gray(0b110), 4);
+        /// assert_eq!(from_gray(to_gray(42)), 42);
+        /// ```
+        #[must_use]
+        #[inline]
+        pub const fn from_gray(base: $primitive_ty) -> $primitive_ty {
+            let mut value = base;
+            let mut shift = 1;
+            while shift < BIT_COUNT {
+                value ^= value >> shift;
+                shift *= 2;
+            }
+            value
+        }
+
+        /// Returns the parity of `base`, i.e., whether an odd number of bits
+        /// is set.
+        ///
+        /// # Parameters
+        ///
+        /// - `base`: Base value to get the parity of.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        #[doc = concat!("use bit_ops::bitops_", stringify!($primitive_ty), "::parity;")]
+        ///
+        /// assert!(!parity(0));
+        /// assert!(parity(0b1));
+        /// assert!(!parity(0b101));
+        /// assert!(parity(0b111));
+        /// ```
+        #[must_use]
+        #[inline]
+        pub const fn parity(base: $primitive_ty) -> bool {
+            base.count_ones() % 2 == 1
+        }
+
+        /// Returns the parity of the specified contiguous bits, i.e., whether
+        /// an odd number of them is set.
+        ///
+        /// # Parameters
+        ///
+        /// - `base`: Base value to get the parity of a specific set of bits
+        ///           from.
+        /// - `bits`: Amount of bits of `base` that are relevant.
+        /// - `shift`: Relevant position of bits inside `base`, starting from
+        ///            the right/LSB (`0`).
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        #[doc = concat!("use bit_ops::bitops_", stringify!($primitive_ty), "::parity_of_field;")]
+        ///
+        /// assert!(!parity_of_field(0b1110, 3, 0));
+        /// assert!(parity_of_field(0b1110, 3, 1));
+        /// ```
+        ///
+        /// # Panics
+        ///
+        /// This function panics for overflowing shifts and bit positions that
+        /// are outside the range of the underlying type.
+        #[must_use]
+        #[inline]
+        pub const fn parity_of_field(
+            base: $primitive_ty,
+            bits: $primitive_ty,
+            shift: $primitive_ty,
+        ) -> bool {
+            parity(get_bits(base, bits, shift))
+        }
+
+        /// Returns the number of set bits in the specified contiguous bits.
+        ///
+        /// # Parameters
+        ///
+        /// - `base`: Base value to count the set bits of a specific set of
+        ///           bits in.
+        /// - `bits`: Amount of bits of `base` that are relevant.
+        /// - `shift`: Relevant position of bits inside `base`, starting from
+        ///            the right/LSB (`0`).
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        #[doc = concat!("use bit_ops::bitops_", stringify!($primitive_ty), "::count_ones_in_field;")]
+        ///
+        /// assert_eq!(count_ones_in_field(0b1110_1101, 4, 0), 3);
+        /// assert_eq!(count_ones_in_field(0b1110_1101, 4, 4), 3);
+        /// assert_eq!(count_ones_in_field(0b1110_1101, 2, 3), 1);
+        /// ```
+        ///
+        /// # Panics
+        ///
+        /// This function panics for overflowing shifts and bit positions that
+        /// are outside the range of the underlying type.
+        #[must_use]
+        #[inline]
+        pub const fn count_ones_in_field(
+            base: $primitive_ty,
+            bits: $primitive_ty,
+            shift: $primitive_ty,
+        ) -> $primitive_ty {
+            get_bits(base, bits, shift).count_ones() as $primitive_ty
+        }
+
+        /// Returns the number of set bits below the given bit position.
+        ///
+        /// # Parameters
+        ///
+        /// - `base`: Base value to count the set bits in.
+        /// - `pos`: Exclusive upper bit position (`0..=BITS`).
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        #[doc = concat!("use bit_ops::bitops_", stringify!($primitive_ty), "::rank;")]
+        ///
+        /// assert_eq!(rank(0b1011_0110, 0), 0);
+        /// assert_eq!(rank(0b1011_0110, 2), 1);
+        /// assert_eq!(rank(0b1011_0110, 5), 3);
+        /// assert_eq!(rank(0b1011_0110, 8), 5);
+        /// ```
+        ///
+        /// # Panics
+        ///
+        /// This function panics for bit positions that are outside the range
+        /// of the underlying type.
+        #[must_use]
+        #[inline]
+        pub const fn rank(base: $primitive_ty, pos: $primitive_ty) -> $primitive_ty {
+            (base & create_mask(pos)).count_ones() as $primitive_ty
+        }
+
+        /// Returns the position of the `n`-th set bit (starting at `0`), if
+        /// any.
+        ///
+        /// This is the inverse of [`rank`]: For every set bit at position
+        /// `pos`, `select(base, rank(base, pos)) == Some(pos)`.
+        ///
+        /// # Parameters
+        ///
+        /// - `base`: Base value to search the set bit in.
+        /// - `n`: Zero-based index of the set bit to search.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        #[doc = concat!("use bit_ops::bitops_", stringify!($primitive_ty), "::select;")]
+        ///
+        /// assert_eq!(select(0b1011_0110, 0), Some(1));
+        /// assert_eq!(select(0b1011_0110, 1), Some(2));
+        /// assert_eq!(select(0b1011_0110, 4), Some(7));
+        /// assert_eq!(select(0b1011_0110, 5), None);
+        /// ```
+        #[must_use]
+        #[inline]
+        pub const fn select(base: $primitive_ty, n: $primitive_ty) -> Option<$primitive_ty> {
+            if n >= base.count_ones() as $primitive_ty {
+                return None;
+            }
+            // Binary search: narrow down the window that contains the bit.
+            let mut value = base;
+            let mut n = n;
+            let mut pos = 0;
+            let mut width = BIT_COUNT;
+            while width > 1 {
+                let half = width / 2;
+                let ones = (value & create_mask(half)).count_ones() as $primitive_ty;
+                if n >= ones {
+                    n -= ones;
+                    value >>= half;
+                    pos += half;
+                }
+                width = half;
+            }
+            Some(pos)
+        }
+
+        /// Like [`select`] but uses the `pdep` instruction.
+        ///
+        /// This is not `const` as the corresponding intrinsic is not.
+        #[cfg(all(target_arch = "x86_64", target_feature = "bmi2"))]
+        #[must_use]
+        #[inline]
+        pub fn select_bmi2(base: $primitive_ty, n: $primitive_ty) -> Option<$primitive_ty> {
+            if n >= base.count_ones() as $primitive_ty {
+                return None;
+            }
+            let bit = super::bmi2::pdep(1 << n, base as u128);
+            Some(bit.trailing_zeros() as $primitive_ty)
+        }
+
+        /// Aligns `base` down to a multiple of `2^align_bits`.
+        ///
+        /// # Parameters
+        ///
+        /// - `base`: Value (e.g., an address) to align.
+        /// - `align_bits`: Alignment as power of two, e.g., `12` for 4 KiB.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        #[doc = concat!("use bit_ops::bitops_", stringify!($primitive_ty), "::align_down;")]
+        ///
+        /// assert_eq!(align_down(0x7f, 4), 0x70);
+        /// assert_eq!(align_down(0x70, 4), 0x70);
+        /// assert_eq!(align_down(0x7f, 0), 0x7f);
+        /// ```
+        ///
+        /// # Panics
+        ///
+        /// This function panics for bit positions that are outside the range
+        /// of the underlying type.
+        #[must_use]
+        #[inline]
+        pub const fn align_down(base: $primitive_ty, align_bits: $primitive_ty) -> $primitive_ty {
+            base & !create_mask(align_bits)
+        }
+
+        /// Aligns `base` up to a multiple of `2^align_bits`, wrapping around
+        /// at the boundary of the type.
+        ///
+        /// Use [`checked_align_up`] to detect the overflow.
+        ///
+        /// # Parameters
+        ///
+        /// - `base`: Value (e.g., an address) to align.
+        /// - `align_bits`: Alignment as power of two, e.g., `12` for 4 KiB.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        #[doc = concat!("use bit_ops::bitops_", stringify!($primitive_ty), "::align_up;")]
+        ///
+        /// assert_eq!(align_up(0x71, 4), 0x80);
+        /// assert_eq!(align_up(0x70, 4), 0x70);
+        #[doc = concat!("assert_eq!(align_up(", stringify!($primitive_ty), "::MAX, 4), 0);")]
+        /// ```
+        ///
+        /// # Panics
+        ///
+        /// This function panics for bit positions that are outside the range
+        /// of the underlying type.
+        #[must_use]
+        #[inline]
+        pub const fn align_up(base: $primitive_ty, align_bits: $primitive_ty) -> $primitive_ty {
+            let mask = create_mask(align_bits);
+            base.wrapping_add(mask) & !mask
+        }
+
+        /// Aligns `base` up to a multiple of `2^align_bits`, if the result is
+        /// representable by the type.
+        ///
+        /// # Parameters
+        ///
+        /// - `base`: Value (e.g., an address) to align.
+        /// - `align_bits`: Alignment as power of two, e.g., `12` for 4 KiB.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        #[doc = concat!("use bit_ops::bitops_", stringify!($primitive_ty), "::checked_align_up;")]
+        ///
+        /// assert_eq!(checked_align_up(0x71, 4), Some(0x80));
+        #[doc = concat!("assert_eq!(checked_align_up(", stringify!($primitive_ty), "::MAX, 4), None);")]
+        /// ```
+        ///
+        /// # Panics
+        ///
+        /// This function panics for bit positions that are outside the range
+        /// of the underlying type.
+        #[must_use]
+        #[inline]
+        pub const fn checked_align_up(
+            base: $primitive_ty,
+            align_bits: $primitive_ty,
+        ) -> Option<$primitive_ty> {
+            let mask = create_mask(align_bits);
+            match base.checked_add(mask) {
+                Some(value) => Some(value & !mask),
+                None => None,
+            }
+        }
+
+        /// Returns whether `base` is a multiple of `2^align_bits`.
+        ///
+        /// # Parameters
+        ///
+        /// - `base`: Value (e.g., an address) to check.
+        /// - `align_bits`: Alignment as power of two, e.g., `12` for 4 KiB.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        #[doc = concat!("use bit_ops::bitops_", stringify!($primitive_ty), "::is_aligned;")]
+        ///
+        /// assert!(is_aligned(0x70, 4));
+        /// assert!(!is_aligned(0x71, 4));
+        /// assert!(is_aligned(0, 7));
+        /// ```
+        ///
+        /// # Panics
+        ///
+        /// This function panics for bit positions that are outside the range
+        /// of the underlying type.
+        #[must_use]
+        #[inline]
+        pub const fn is_aligned(base: $primitive_ty, align_bits: $primitive_ty) -> bool {
+            base & create_mask(align_bits) == 0
+        }
+
+        /// Returns whether `base` is a mask of contiguous low bits, i.e.,
+        /// whether `base + 1` is a power of two (or `base` is the maximum
+        /// value of the type).
+        ///
+        /// # Parameters
+        ///
+        /// - `base`: Value to check.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        #[doc = concat!("use bit_ops::bitops_", stringify!($primitive_ty), "::is_power_of_two_mask;")]
+        ///
+        /// assert!(is_power_of_two_mask(0));
+        /// assert!(is_power_of_two_mask(0b111));
+        #[doc = concat!("assert!(is_power_of_two_mask(", stringify!($primitive_ty), "::MAX));")]
+        /// assert!(!is_power_of_two_mask(0b110));
+        /// assert!(!is_power_of_two_mask(0b1011));
+        /// ```
+        #[must_use]
+        #[inline]
+        pub const fn is_power_of_two_mask(base: $primitive_ty) -> bool {
+            base & base.wrapping_add(1) == 0
+        }
+
+        /// Returns the order `k` of the largest naturally aligned block of
+        /// size `2^k` that starts at `addr` and fits into `size`, if any.
+        ///
+        /// This is typically used to select the largest possible page size
+        /// when creating page-table mappings.
+        ///
+        /// # Parameters
+        ///
+        /// - `addr`: Start address of the block.
+        /// - `size`: Available size from `addr` on.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        #[doc = concat!("use bit_ops::bitops_", stringify!($primitive_ty), "::largest_aligned_block;")]
+        ///
+        /// assert_eq!(largest_aligned_block(0x40, 0x7f), Some(6));
+        /// assert_eq!(largest_aligned_block(0x40, 0x3f), Some(5));
+        /// assert_eq!(largest_aligned_block(0x44, 0x7f), Some(2));
+        /// assert_eq!(largest_aligned_block(0, 0x10), Some(4));
+        /// assert_eq!(largest_aligned_block(0x40, 0), None);
+        /// ```
+        #[must_use]
+        #[inline]
+        pub const fn largest_aligned_block(
+            addr: $primitive_ty,
+            size: $primitive_ty,
+        ) -> Option<$primitive_ty> {
+            let size_order = match highest_bit(size) {
+                Some(order) => order,
+                None => return None,
+            };
+            let align_order = match lowest_bit(addr) {
+                Some(order) => order,
+                None => BIT_COUNT,
+            };
+            if align_order < size_order {
+                Some(align_order)
+            } else {
+                Some(size_order)
+            }
+        }
+
+        /// Rounds `base` up to the next power of two, if it is representable
+        /// by the type.
+        ///
+        /// Powers of two are returned unchanged; `0` is rounded to `1`.
+        ///
+        /// # Parameters
+        ///
+        /// - `base`: Value to round.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        #[doc = concat!("use bit_ops::bitops_", stringify!($primitive_ty), "::round_to_pow2;")]
+        ///
+        /// assert_eq!(round_to_pow2(0), Some(1));
+        /// assert_eq!(round_to_pow2(5), Some(8));
+        /// assert_eq!(round_to_pow2(64), Some(64));
+        #[doc = concat!("assert_eq!(round_to_pow2(", stringify!($primitive_ty), "::MAX), None);")]
+        /// ```
+        #[must_use]
+        #[inline]
+        pub const fn round_to_pow2(base: $primitive_ty) -> Option<$primitive_ty> {
+            base.checked_next_power_of_two()
+        }
+
+        /// Rotates the specified contiguous bits to the left (towards the
+        /// MSB) by `n` bits while leaving all other bits of `base` unchanged.
+        ///
+        /// Bits rotated out at the top of the field re-enter at the bottom of
+        /// the field. `n` may be larger than `bits`.
+        ///
+        /// # Parameters
+        ///
+        /// - `base`: Base value to alter.
+        /// - `bits`: Amount of bits of `base` that are relevant.
+        /// - `shift`: Relevant position of bits inside `base`, starting from
+        ///            the right/LSB (`0`).
+        /// - `n`: Amount of bits to rotate.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        #[doc = concat!("use bit_ops::bitops_", stringify!($primitive_ty), "::rotate_field_left;")]
+        ///
+        /// assert_eq!(rotate_field_left(0b1_1001_1, 4, 1, 1), 0b1_0011_1);
+        /// assert_eq!(rotate_field_left(0b1_1001_1, 4, 1, 2), 0b1_0110_1);
+        /// assert_eq!(rotate_field_left(0b1_1001_1, 4, 1, 4), 0b1_1001_1);
+        /// ```
+        ///
+        /// # Panics
+        ///
+        /// This function panics for overflowing shifts and bit positions that
+        /// are outside the range of the underlying type.
+        #[must_use]
+        #[inline]
+        pub const fn rotate_field_left(
+            base: $primitive_ty,
+            bits: $primitive_ty,
+            shift: $primitive_ty,
+            n: $primitive_ty,
+        ) -> $primitive_ty {
+            let field = get_bits(base, bits, shift);
+            if bits == 0 || n % bits == 0 {
+                return base;
+            }
+            let n = n % bits;
+            let field = ((field << n) | (field >> (bits - n))) & create_mask(bits);
+            set_bits_exact(base, field, bits, shift)
+        }
+
+        /// Rotates the specified contiguous bits to the right (towards the
+        /// LSB) by `n` bits while leaving all other bits of `base` unchanged.
+        ///
+        /// Bits rotated out at the bottom of the field re-enter at the top of
+        /// the field. `n` may be larger than `bits`.
+        ///
+        /// # Parameters
+        ///
+        /// - `base`: Base value to alter.
+        /// - `bits`: Amount of bits of `base` that are relevant.
+        /// - `shift`: Relevant position of bits inside `base`, starting from
+        ///            the right/LSB (`0`).
+        /// - `n`: Amount of bits to rotate.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        #[doc = concat!("use bit_ops::bitops_", stringify!($primitive_ty), "::rotate_field_right;")]
+        ///
+        /// assert_eq!(rotate_field_right(0b1_1001_1, 4, 1, 1), 0b1_1100_1);
+        /// assert_eq!(rotate_field_right(0b1_1001_1, 4, 1, 2), 0b1_0110_1);
+        /// assert_eq!(rotate_field_right(0b1_1001_1, 4, 1, 5), 0b1_1100_1);
+        /// ```
+        ///
+        /// # Panics
+        ///
+        /// This function panics for overflowing shifts and bit positions that
+        /// are outside the range of the underlying type.
+        #[must_use]
+        #[inline]
+        pub const fn rotate_field_right(
+            base: $primitive_ty,
+            bits: $primitive_ty,
+            shift: $primitive_ty,
+            n: $primitive_ty,
+        ) -> $primitive_ty {
+            if bits == 0 {
+                return rotate_field_left(base, bits, shift, 0);
+            }
+            rotate_field_left(base, bits, shift, bits - n % bits)
+        }
+
+        /// Returns a value with only the lowest set bit of `base` set.
+        ///
+        /// Returns `0` if `base` is `0`.
+        ///
+        /// # Parameters
+        ///
+        /// - `base`: Base value.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        #[doc = concat!("use bit_ops::bitops_", stringify!($primitive_ty), "::isolate_lowest_set;")]
+        ///
+        /// assert_eq!(isolate_lowest_set(0b1011_0100), 0b0000_0100);
+        /// assert_eq!(isolate_lowest_set(0b1000_0000), 0b1000_0000);
+        /// assert_eq!(isolate_lowest_set(0), 0);
+        /// ```
+        #[must_use]
+        #[inline]
+        pub const fn isolate_lowest_set(base: $primitive_ty) -> $primitive_ty {
+            base & base.wrapping_neg()
+        }
+
+        /// Like [`isolate_lowest_set`] but uses the `blsi` instruction.
+        ///
+        /// This is not `const` as the corresponding intrinsic is not.
+        #[cfg(all(target_arch = "x86_64", target_feature = "bmi1"))]
+        #[must_use]
+        #[inline]
+        pub fn isolate_lowest_set_bmi1(base: $primitive_ty) -> $primitive_ty {
+            super::bmi1::blsi(base as u128) as $primitive_ty
+        }
+
+        /// Clears the lowest set bit of `base`.
+        ///
+        /// Returns `0` if `base` is `0`.
+        ///
+        /// # Parameters
+        ///
+        /// - `base`: Base value.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        #[doc = concat!("use bit_ops::bitops_", stringify!($primitive_ty), "::clear_lowest_set;")]
+        ///
+        /// assert_eq!(clear_lowest_set(0b1011_0100), 0b1011_0000);
+        /// assert_eq!(clear_lowest_set(0b1000_0000), 0);
+        /// assert_eq!(clear_lowest_set(0), 0);
+        /// ```
+        #[must_use]
+        #[inline]
+        pub const fn clear_lowest_set(base: $primitive_ty) -> $primitive_ty {
+            base & base.wrapping_sub(1)
+        }
+
+        /// Like [`clear_lowest_set`] but uses the `blsr` instruction.
+        ///
+        /// This is not `const` as the corresponding intrinsic is not.
+        #[cfg(all(target_arch = "x86_64", target_feature = "bmi1"))]
+        #[must_use]
+        #[inline]
+        pub fn clear_lowest_set_bmi1(base: $primitive_ty) -> $primitive_ty {
+            super::bmi1::blsr(base as u128) as $primitive_ty
+        }
+
+        /// Returns a value with only the lowest cleared bit of `base` set.
+        ///
+        /// Returns `0` if all bits of `base` are set.
+        ///
+        /// # Parameters
+        ///
+        /// - `base`: Base value.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        #[doc = concat!("use bit_ops::bitops_", stringify!($primitive_ty), "::isolate_lowest_zero;")]
+        ///
+        /// assert_eq!(isolate_lowest_zero(0b1011_0111), 0b0000_1000);
+        /// assert_eq!(isolate_lowest_zero(0), 1);
+        #[doc = concat!("assert_eq!(isolate_lowest_zero(", stringify!($primitive_ty), "::MAX), 0);")]
+        /// ```
+        #[must_use]
+        #[inline]
+        pub const fn isolate_lowest_zero(base: $primitive_ty) -> $primitive_ty {
+            !base & base.wrapping_add(1)
+        }
+
+        /// Sets all bits below the highest set bit of `base`.
+        ///
+        /// The result is the smallest value of the form `2^n - 1` that is
+        /// greater than or equal to `base`.
+        ///
+        /// # Parameters
+        ///
+        /// - `base`: Base value.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        #[doc = concat!("use bit_ops::bitops_", stringify!($primitive_ty), "::smear_right;")]
+        ///
+        /// assert_eq!(smear_right(0b0010_0100), 0b0011_1111);
+        /// assert_eq!(smear_right(0b1000_0000), 0b1111_1111);
+        /// assert_eq!(smear_right(0), 0);
+        /// ```
+        #[must_use]
+        #[inline]
+        pub const fn smear_right(base: $primitive_ty) -> $primitive_ty {
+            if base == 0 {
+                0
+            } else {
+                <$primitive_ty>::MAX >> base.leading_zeros()
+            }
+        }
+
+        /// Returns a mask of the lowest set bit of `base` and all bits below it.
+        ///
+        /// If `base` is `0`, all bits are set. This matches the `blsmsk`
+        /// instruction.
+        ///
+        /// # Parameters
+        ///
+        /// - `base`: Base value.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        #[doc = concat!("use bit_ops::bitops_", stringify!($primitive_ty), "::mask_up_to_lowest_set;")]
+        ///
+        /// assert_eq!(mask_up_to_lowest_set(0b1011_0100), 0b0000_0111);
+        /// assert_eq!(mask_up_to_lowest_set(0b0000_0001), 0b0000_0001);
+        #[doc = concat!("assert_eq!(mask_up_to_lowest_set(0), ", stringify!($primitive_ty), "::MAX);")]
+        /// ```
+        #[must_use]
+        #[inline]
+        pub const fn mask_up_to_lowest_set(base: $primitive_ty) -> $primitive_ty {
+            base ^ base.wrapping_sub(1)
+        }
+
+        /// Like [`mask_up_to_lowest_set`] but uses the `blsmsk` instruction.
+        ///
+        /// This is not `const` as the corresponding intrinsic is not.
+        #[cfg(all(target_arch = "x86_64", target_feature = "bmi1"))]
+        #[must_use]
+        #[inline]
+        pub fn mask_up_to_lowest_set_bmi1(base: $primitive_ty) -> $primitive_ty {
+            super::bmi1::blsmsk(base as u128) as $primitive_ty
+        }
+
+        /// Returns the next larger value with the same amount of set bits
+        /// (Gosper's hack), if any.
+        ///
+        /// This is useful to enumerate all subsets of a given size in
+        /// ascending order. Returns `None` if `base` is `0` or if there is no
+        /// larger value with the same amount of set bits.
+        ///
+        /// # Parameters
+        ///
+        /// - `base`: Base value.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        #[doc = concat!("use bit_ops::bitops_", stringify!($primitive_ty), "::next_same_popcount;")]
+        ///
+        /// assert_eq!(next_same_popcount(0b0011), Some(0b0101));
+        /// assert_eq!(next_same_popcount(0b0101), Some(0b0110));
+        /// assert_eq!(next_same_popcount(0b0110), Some(0b1001));
+        /// assert_eq!(next_same_popcount(0), None);
+        #[doc = concat!("assert_eq!(next_same_popcount(", stringify!($primitive_ty), "::MAX), None);")]
+        /// ```
+        #[must_use]
+        #[inline]
+        pub const fn next_same_popcount(base: $primitive_ty) -> Option<$primitive_ty> {
+            if base == 0 {
+                return None;
+            }
+            let lowest = isolate_lowest_set(base);
+            let Some(ripple) = base.checked_add(lowest) else {
+                return None;
+            };
+            let ones = ((ripple ^ base) >> 2) >> base.trailing_zeros();
+            Some(ripple | ones)
+        }
+
+        /// Returns the lowest set bit at or above position `from`, if any.
+        ///
+        /// # Parameters
+        ///
+        /// - `base`: Base value to scan.
+        /// - `from`: Lowest bit position to check (`0..=BITS`). For `BITS`, the
+        ///   result is always `None`.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        #[doc = concat!("use bit_ops::bitops_", stringify!($primitive_ty), "::next_set_bit;")]
+        ///
+        /// assert_eq!(next_set_bit(0b1001_0010, 0), Some(1));
+        /// assert_eq!(next_set_bit(0b1001_0010, 1), Some(1));
+        /// assert_eq!(next_set_bit(0b1001_0010, 2), Some(4));
+        /// assert_eq!(next_set_bit(0b1001_0010, 5), Some(7));
+        /// assert_eq!(next_set_bit(0b1001_0010, 8), None);
+        /// ```
+        ///
+        /// # Panics
+        ///
+        /// This function panics if `from` is larger than the number of bits
+        /// of the underlying type.
+        #[must_use]
+        #[inline]
+        pub const fn next_set_bit(base: $primitive_ty, from: $primitive_ty) -> Option<$primitive_ty> {
+            assert_in_range(from, true);
+            if from == BIT_COUNT {
+                return None;
+            }
+            lowest_bit(base & (<$primitive_ty>::MAX << from))
+        }
+
+        /// Returns the highest set bit strictly below position `from`, if any.
+        ///
+        /// # Parameters
+        ///
+        /// - `base`: Base value to scan.
+        /// - `from`: Exclusive upper bit position (`0..=BITS`). `BITS` scans
+        ///   the whole value.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        #[doc = concat!("use bit_ops::bitops_", stringify!($primitive_ty), "::prev_set_bit;")]
+        ///
+        /// assert_eq!(prev_set_bit(0b1001_0010, 8), Some(7));
+        /// assert_eq!(prev_set_bit(0b1001_0010, 7), Some(4));
+        /// assert_eq!(prev_set_bit(0b1001_0010, 4), Some(1));
+        /// assert_eq!(prev_set_bit(0b1001_0010, 1), None);
+        /// assert_eq!(prev_set_bit(0b1001_0010, 0), None);
+        /// ```
+        ///
+        /// # Panics
+        ///
+        /// This function panics if `from` is larger than the number of bits
+        /// of the underlying type.
+        #[must_use]
+        #[inline]
+        pub const fn prev_set_bit(base: $primitive_ty, from: $primitive_ty) -> Option<$primitive_ty> {
+            assert_in_range(from, true);
+            if from == 0 {
+                return None;
+            }
+            highest_bit(base & (<$primitive_ty>::MAX >> (BIT_COUNT - from)))
+        }
+
+        /// Returns the lowest cleared bit at or above position `from`, if any.
+        ///
+        /// # Parameters
+        ///
+        /// - `base`: Base value to scan.
+        /// - `from`: Lowest bit position to check (`0..=BITS`). For `BITS`, the
+        ///   result is always `None`.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        #[doc = concat!("use bit_ops::bitops_", stringify!($primitive_ty), "::next_clear_bit;")]
+        ///
+        /// assert_eq!(next_clear_bit(0b0110_1101, 0), Some(1));
+        /// assert_eq!(next_clear_bit(0b0110_1101, 2), Some(4));
+        /// assert_eq!(next_clear_bit(0b0110_1101, 5), Some(7));
+        /// ```
+        ///
+        /// # Panics
+        ///
+        /// This function panics if `from` is larger than the number of bits
+        /// of the underlying type.
+        #[must_use]
+        #[inline]
+        pub const fn next_clear_bit(base: $primitive_ty, from: $primitive_ty) -> Option<$primitive_ty> {
+            next_set_bit(!base, from)
+        }
+
+        /// Returns the highest cleared bit strictly below position `from`, if
+        /// any.
+        ///
+        /// # Parameters
+        ///
+        /// - `base`: Base value to scan.
+        /// - `from`: Exclusive upper bit position (`0..=BITS`). `BITS` scans
+        ///   the whole value.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        #[doc = concat!("use bit_ops::bitops_", stringify!($primitive_ty), "::prev_clear_bit;")]
+        ///
+        /// assert_eq!(prev_clear_bit(0b0110_1101, 7), Some(4));
+        /// assert_eq!(prev_clear_bit(0b0110_1101, 4), Some(1));
+        /// assert_eq!(prev_clear_bit(0b0110_1101, 1), None);
+        /// ```
+        ///
+        /// # Panics
+        ///
+        /// This function panics if `from` is larger than the number of bits
+        /// of the underlying type.
+        #[must_use]
+        #[inline]
+        pub const fn prev_clear_bit(base: $primitive_ty, from: $primitive_ty) -> Option<$primitive_ty> {
+            prev_set_bit(!base, from)
+        }
+
+        /// Returns the length of the run of ones that starts at the given
+        /// bit position and extends towards the MSB.
+        ///
+        /// The bit position starts at `0`. Returns `0` if the bit at `pos` is
+        /// not set.
+        ///
+        /// # Parameters
+        ///
+        /// - `base`: Base value to scan.
+        /// - `pos`: Bit position where the run starts.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        #[doc = concat!("use bit_ops::bitops_", stringify!($primitive_ty), "::run_of_ones_at;")]
+        ///
+        /// assert_eq!(run_of_ones_at(0b0111_0110, 1), 2);
+        /// assert_eq!(run_of_ones_at(0b0111_0110, 2), 1);
+        /// assert_eq!(run_of_ones_at(0b0111_0110, 3), 0);
+        /// assert_eq!(run_of_ones_at(0b0111_0110, 4), 3);
+        /// ```
+        ///
+        /// # Panics
+        ///
+        /// This function panics for bit positions that are outside the range
+        /// of the underlying type.
+        #[must_use]
+        #[inline]
+        pub const fn run_of_ones_at(base: $primitive_ty, pos: $primitive_ty) -> $primitive_ty {
+            assert_in_range(pos, false);
+            (base >> pos).trailing_ones() as $primitive_ty
+        }
+
+        /// Returns the start position and the length of the longest run of
+        /// ones.
+        ///
+        /// The bit position starts at `0`. If there are multiple runs with
+        /// the maximum length, the lowest one is returned. Returns `(0, 0)` if
+        /// `base` is `0`.
+        ///
+        /// # Parameters
+        ///
+        /// - `base`: Base value to scan.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        #[doc = concat!("use bit_ops::bitops_", stringify!($primitive_ty), "::longest_run_of_ones;")]
+        ///
+        /// assert_eq!(longest_run_of_ones(0b0111_0110), (4, 3));
+        /// assert_eq!(longest_run_of_ones(0b0110_0110), (1, 2));
+        /// assert_eq!(longest_run_of_ones(0), (0, 0));
+        #[doc = concat!("assert_eq!(longest_run_of_ones(", stringify!($primitive_ty), "::MAX), (0, ", stringify!($primitive_ty), "::BITS as ", stringify!($primitive_ty), "));")]
+        /// ```
+        #[must_use]
+        #[inline]
+        pub const fn longest_run_of_ones(base: $primitive_ty) -> ($primitive_ty, $primitive_ty) {
+            let mut longest = (0, 0);
+            let mut pos = 0;
+            while pos < BIT_COUNT {
+                let Some(start) = next_set_bit(base, pos) else {
+                    break;
+                };
+                let len = run_of_ones_at(base, start);
+                if len > longest.1 {
+                    longest = (start, len);
+                }
+                pos = start + len;
+            }
+            longest
+        }
+
+        /// Returns the lowest position of a run of at least `n` cleared bits,
+        /// if any.
+        ///
+        /// This is useful to allocate a contiguous range of resources
+        /// tracked in a bitmask, where set bits mark used resources. The bit
+        /// position starts at `0`. For `n == 0`, this returns `Some(0)`.
+        ///
+        /// # Parameters
+        ///
+        /// - `base`: Base value to scan.
+        /// - `n`: Required amount of consecutive cleared bits.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        #[doc = concat!("use bit_ops::bitops_", stringify!($primitive_ty), "::first_run_of_zeros_with_len;")]
+        ///
+        /// assert_eq!(first_run_of_zeros_with_len(0b1100_0101, 1), Some(1));
+        /// assert_eq!(first_run_of_zeros_with_len(0b1100_0101, 2), Some(3));
+        /// assert_eq!(first_run_of_zeros_with_len(0b1100_0101, 3), Some(3));
+        #[doc = concat!("assert_eq!(first_run_of_zeros_with_len(", stringify!($primitive_ty), "::MAX, 1), None);")]
+        /// ```
+        #[must_use]
+        #[inline]
+        pub const fn first_run_of_zeros_with_len(
+            base: $primitive_ty,
+            n: $primitive_ty,
+        ) -> Option<$primitive_ty> {
+            if n == 0 {
+                return Some(0);
+            }
+            let mut pos = 0;
+            while pos < BIT_COUNT {
+                let Some(start) = next_clear_bit(base, pos) else {
+                    break;
+                };
+                let len = run_of_ones_at(!base, start);
+                if len >= n {
+                    return Some(start);
+                }
+                pos = start + len;
+            }
+            None
+        }
+
+        /// Returns the number of runs of ones, i.e., the number of maximal
+        /// groups of consecutive set bits.
+        ///
+        /// # Parameters
+        ///
+        /// - `base`: Base value to scan.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        #[doc = concat!("use bit_ops::bitops_", stringify!($primitive_ty), "::count_runs;")]
+        ///
+        /// assert_eq!(count_runs(0b0111_0110), 2);
+        /// assert_eq!(count_runs(0b0101_0101), 4);
+        /// assert_eq!(count_runs(0), 0);
+        #[doc = concat!("assert_eq!(count_runs(", stringify!($primitive_ty), "::MAX), 1);")]
+        /// ```
+        #[must_use]
+        #[inline]
+        pub const fn count_runs(base: $primitive_ty) -> $primitive_ty {
+            // Each run starts at a set bit whose lower neighbour is cleared.
+            (base & !(base << 1)).count_ones() as $primitive_ty
+        }
+
+        /// Parses a bit pattern string into a `(mask, value)` pair.
+        ///
+        /// The pattern is written from the MSB to the LSB, like a binary
+        /// literal, and must specify all bits of the underlying type. The
+        /// following characters are supported:
+        ///
+        /// - `0` and `1`: fixed bit, i.e., set in the mask and set to the
+        ///   given value in the value.
+        /// - `x`, `X`, and `.`: don't-care bit, i.e., cleared in the mask and
+        ///   in the value.
+        /// - `_`: separator without meaning.
+        ///
+        /// Use [`matches_pattern`] to check values against the result. When
+        /// evaluated in a `const` context, invalid patterns are compile-time
+        /// errors.
+        ///
+        /// # Parameters
+        ///
+        /// - `pattern`: Bit pattern string.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        #[doc = concat!("use bit_ops::bitops_", stringify!($primitive_ty), "::parse_pattern;")]
+        ///
+        #[doc = concat!("const PATTERN: (", stringify!($primitive_ty), ", ", stringify!($primitive_ty), ") =")]
+        #[doc = concat!("    parse_pattern(\"", example_pattern!($primitive_ty), "\");")]
+        #[doc = concat!("const SHIFT: u32 = ", stringify!($primitive_ty), "::BITS - 8;")]
+        /// assert_eq!(PATTERN, (0b0011_0010 << SHIFT, 0b0001_0010 << SHIFT));
+        /// ```
+        ///
+        /// Invalid patterns fail to compile in `const` contexts:
+        ///
+        /// ```rust,compile_fail
+        #[doc = concat!("use bit_ops::bitops_", stringify!($primitive_ty), "::parse_pattern;")]
+        ///
+        /// // one bit too many
+        #[doc = concat!("const PATTERN: (", stringify!($primitive_ty), ", ", stringify!($primitive_ty), ") =")]
+        #[doc = concat!("    parse_pattern(\"x", example_pattern!($primitive_ty), "\");")]
+        /// ```
+        ///
+        /// # Panics
+        ///
+        /// This function panics if the pattern contains invalid characters
+        /// or if the amount of bits in the pattern doesn't match the amount
+        /// of bits of the underlying type.
+        #[must_use]
+        #[inline]
+        pub const fn parse_pattern(pattern: &str) -> ($primitive_ty, $primitive_ty) {
+            let bytes = pattern.as_bytes();
+            let mut mask = 0;
+            let mut value = 0;
+            let mut bits = 0;
+            let mut i = 0;
+            while i < bytes.len() {
+                let (mask_bit, value_bit) = match bytes[i] {
+                    b'_' => {
+                        i += 1;
+                        continue;
+                    }
+                    b'0' => (1, 0),
+                    b'1' => (1, 1),
+                    b'x' | b'X' | b'.' => (0, 0),
+                    _ => panic!("invalid character in bit pattern"),
+                };
+                assert!(
+                    bits < BIT_COUNT,
+                    "bit pattern has more bits than the underlying type"
+                );
+                mask = (mask << 1) | mask_bit;
+                value = (value << 1) | value_bit;
+                bits += 1;
+                i += 1;
+            }
+            assert!(
+                bits == BIT_COUNT,
+                "bit pattern has fewer bits than the underlying type"
+            );
+            (mask, value)
+        }
+
+        /// Parses a bit pattern string and returns its mask.
+        ///
+        /// This is the first element of [`parse_pattern`]. See there for the
+        /// syntax of the pattern.
+        ///
+        /// # Parameters
+        ///
+        /// - `pattern`: Bit pattern string.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        #[doc = concat!("use bit_ops::bitops_", stringify!($primitive_ty), "::pattern_mask;")]
+        ///
+        #[doc = concat!("const MASK: ", stringify!($primitive_ty), " = pattern_mask(\"", example_pattern!($primitive_ty), "\");")]
+        #[doc = concat!("assert_eq!(MASK, 0b0011_0010 << (", stringify!($primitive_ty), "::BITS - 8));")]
+        /// ```
+        ///
+        /// # Panics
+        ///
+        /// This function panics for invalid patterns.
+        #[must_use]
+        #[inline]
+        pub const fn pattern_mask(pattern: &str) -> $primitive_ty {
+            parse_pattern(pattern).0
+        }
+
+        /// Parses a bit pattern string and returns its value.
+        ///
+        /// This is the second element of [`parse_pattern`]. See there for the
+        /// syntax of the pattern.
+        ///
+        /// # Parameters
+        ///
+        /// - `pattern`: Bit pattern string.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        #[doc = concat!("use bit_ops::bitops_", stringify!($primitive_ty), "::pattern_value;")]
+        ///
+        #[doc = concat!("const VALUE: ", stringify!($primitive_ty), " = pattern_value(\"", example_pattern!($primitive_ty), "\");")]
+        #[doc = concat!("assert_eq!(VALUE, 0b0001_0010 << (", stringify!($primitive_ty), "::BITS - 8));")]
+        /// ```
+        ///
+        /// # Panics
+        ///
+        /// This function panics for invalid patterns.
+        #[must_use]
+        #[inline]
+        pub const fn pattern_value(pattern: &str) -> $primitive_ty {
+            parse_pattern(pattern).1
+        }
+
+        /// Checks whether all bits of `base` that are set in `mask` equal the
+        /// corresponding bits in `value`.
+        ///
+        /// # Parameters
+        ///
+        /// - `base`: Value to check.
+        /// - `mask`: Relevant bits.
+        /// - `value`: Expected value of the relevant bits.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        #[doc = concat!("use bit_ops::bitops_", stringify!($primitive_ty), "::matches_pattern;")]
+        ///
+        /// assert!(matches_pattern(0b1101, 0b0111, 0b0101));
+        /// assert!(!matches_pattern(0b1111, 0b0111, 0b0101));
+        /// ```
+        #[must_use]
+        #[inline]
+        pub const fn matches_pattern(
+            base: $primitive_ty,
+            mask: $primitive_ty,
+            value: $primitive_ty,
+        ) -> bool {
+            base & mask == value & mask
+        }
+    };
+}
+
+/// Implements the MSB-0 flavour of the bit operations for the specified
+/// primitive type on top of the LSB-0 functions generated by
+/// [`impl_bit_ops`].
+///
+/// In MSB-0 numbering, bit `0` is the most significant bit and bit
+/// `BITS - 1` is the least significant bit.
+macro_rules! impl_bit_ops_msb0 {
+    ($primitive_ty:ty) => {
+        /// Converts an MSB-0 bit position to the corresponding LSB-0 bit
+        /// position.
+        #[track_caller]
+        const fn to_lsb0(bit: $primitive_ty) -> $primitive_ty {
+            super::assert_in_range(bit, false);
+            super::BIT_COUNT - 1 - bit
+        }
+
+        /// Converts an inclusive MSB-0 range `first..=last` to the
+        /// corresponding `(bits, shift)` pair of the LSB-0 functions.
+        #[track_caller]
+        const fn to_bits_and_shift(
+            first: $primitive_ty,
+            last: $primitive_ty,
+        ) -> ($primitive_ty, $primitive_ty) {
+            assert!(
+                first <= last,
+                "first bit position must be less than or equal to last bit position"
+            );
+            let shift = to_lsb0(last);
+            let bits = last - first + 1;
+            (bits, shift)
+        }
+
+        /// Sets the given bit to `1`.
+        ///
+        /// The bit position starts at `0`, which is the most significant bit.
+        ///
+        /// # Parameters
+        ///
+        /// - `base`: Base value to alter.
+        /// - `bit`: Bit to set, starting at position `0` (MSB).
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        #[doc = concat!("use bit_ops::bitops_", stringify!($primitive_ty), "::msb0::set_bit;")]
+        ///
+        /// let raw = set_bit(0, 0);
+        #[doc = concat!("assert_eq!(raw, 1 << (", stringify!($primitive_ty), "::BITS - 1));")]
+        /// ```
+        ///
+        /// # Panics
+        /// This function panics for bit positions that are outside the range of
+        /// the underlying type.
+        #[must_use]
+        #[inline]
+        pub const fn set_bit(base: $primitive_ty, bit: $primitive_ty) -> $primitive_ty {
+            super::set_bit(base, to_lsb0(bit))
+        }
+
+        /// Sets the given bit to the given value.
+        ///
+        /// The bit position starts at `0`, which is the most significant bit.
+        ///
+        /// # Parameters
+        ///
+        /// - `base`: Base value to alter.
+        /// - `bit`: Bit to set, starting at position `0` (MSB).
+        /// - `value`: Value to set.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        #[doc = concat!("use bit_ops::bitops_", stringify!($primitive_ty), "::msb0::set_bit_exact;")]
+        ///
+        #[doc = concat!("let last = ", stringify!($primitive_ty), "::BITS as ", stringify!($primitive_ty), " - 1;")]
+        /// assert_eq!(set_bit_exact(0, last, true), 1);
+        /// assert_eq!(set_bit_exact(1, last, false), 0);
+        /// ```
+        ///
+        /// # Panics
+        /// This function panics for bit positions that are outside the range of
+        /// the underlying type.
+        #[must_use]
+        #[inline]
+        pub const fn set_bit_exact(
+            base: $primitive_ty,
+            bit: $primitive_ty,
+            value: bool,
+        ) -> $primitive_ty {
+            super::set_bit_exact(base, to_lsb0(bit), value)
+        }
+
+        /// Clears the given bit by setting it to `0`.
+        ///
+        /// The bit position starts at `0`, which is the most significant bit.
+        ///
+        /// # Parameters
+        ///
+        /// - `base`: Base value to alter.
+        /// - `bit`: Bit to clear, starting at position `0` (MSB).
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        #[doc = concat!("use bit_ops::bitops_", stringify!($primitive_ty), "::msb0::clear_bit;")]
+        ///
+        #[doc = concat!("assert_eq!(clear_bit(", stringify!($primitive_ty), "::MAX, 0), ", stringify!($primitive_ty), "::MAX >> 1);")]
+        /// ```
+        ///
+        /// # Panics
+        /// This function panics for bit positions that are outside the range of
+        /// the underlying type.
+        #[must_use]
+        #[inline]
+        pub const fn clear_bit(base: $primitive_ty, bit: $primitive_ty) -> $primitive_ty {
+            super::clear_bit(base, to_lsb0(bit))
+        }
+
+        /// Returns whether the given bit is set.
+        ///
+        /// The bit position starts at `0`, which is the most significant bit.
+        ///
+        /// # Parameters
+        ///
+        /// - `base`: Base value to check.
+        /// - `bit`: Bit to check, starting at position `0` (MSB).
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        #[doc = concat!("use bit_ops::bitops_", stringify!($primitive_ty), "::msb0::is_set;")]
+        ///
+        /// assert!(!is_set(1, 0));
+        #[doc = concat!("assert!(is_set(", stringify!($primitive_ty), "::MAX >> 1, 1));")]
+        /// ```
+        ///
+        /// # Panics
+        /// This function panics for bit positions that are outside the range of
+        /// the underlying type.
+        #[must_use]
+        #[inline]
+        pub const fn is_set(base: $primitive_ty, bit: $primitive_ty) -> bool {
+            super::is_set(base, to_lsb0(bit))
+        }
+
+        /// Returns the integer value of the given bit (`0` or `1`).
+        ///
+        /// The bit position starts at `0`, which is the most significant bit.
+        ///
+        /// # Parameters
+        ///
+        /// - `base`: Base value to get a bit from.
+        /// - `bit`: Bit to get, starting at position `0` (MSB).
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        #[doc = concat!("use bit_ops::bitops_", stringify!($primitive_ty), "::msb0::get_bit;")]
+        ///
+        /// assert_eq!(get_bit(1, 0), 0);
+        #[doc = concat!("assert_eq!(get_bit(", stringify!($primitive_ty), "::MAX >> 1, 1), 1);")]
+        /// ```
+        ///
+        /// # Panics
+        /// This function panics for bit positions that are outside the range of
+        /// the underlying type.
+        #[must_use]
+        #[inline]
+        pub const fn get_bit(base: $primitive_ty, bit: $primitive_ty) -> $primitive_ty {
+            super::get_bit(base, to_lsb0(bit))
+        }
+
+        /// Toggles (flips) the given bit.
+        ///
+        /// The bit position starts at `0`, which is the most significant bit.
+        ///
+        /// # Parameters
+        ///
+        /// - `base`: Base value to alter.
+        /// - `bit`: Bit to toggle, starting at position `0` (MSB).
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        #[doc = concat!("use bit_ops::bitops_", stringify!($primitive_ty), "::msb0::toggle_bit;")]
+        ///
+        #[doc = concat!("assert_eq!(toggle_bit(", stringify!($primitive_ty), "::MAX, 0), ", stringify!($primitive_ty), "::MAX >> 1);")]
+        /// ```
+        ///
+        /// # Panics
+        /// This function panics for bit positions that are outside the range of
+        /// the underlying type.
+        #[must_use]
+        #[inline]
+        pub const fn toggle_bit(base: $primitive_ty, bit: $primitive_ty) -> $primitive_ty {
+            super::toggle_bit(base, to_lsb0(bit))
+        }
+
+        /// Toggles (flips) the bits in the inclusive range `first..=last`.
+        ///
+        /// # Parameters
+        ///
+        /// - `base`: Base value to alter.
+        /// - `first`: First (most significant) bit of the range, starting at
+        ///            position `0` (MSB).
+        /// - `last`: Last (least significant) bit of the range.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        #[doc = concat!("use bit_ops::bitops_", stringify!($primitive_ty), "::msb0::toggle_bits;")]
+        ///
+        #[doc = concat!("assert_eq!(toggle_bits(0, 0, 1), 0b11 << (", stringify!($primitive_ty), "::BITS - 2));")]
+        /// ```
+        ///
+        /// # Panics
+        ///
+        /// This function panics if `first > last` and for bit positions that
+        /// are outside the range of the underlying type.
+        #[must_use]
+        #[inline]
+        pub const fn toggle_bits(
+            base: $primitive_ty,
+            first: $primitive_ty,
+            last: $primitive_ty,
+        ) -> $primitive_ty {
+            let (bits, shift) = to_bits_and_shift(first, last);
+            super::toggle_bits(base, bits, shift)
+        }
+
+        /// Sets the bits of `value` in the inclusive range `first..=last` of
+        /// `base` without clearing already set bits.
+        ///
+        /// # Parameters
+        ///
+        /// - `base`: Base value to alter.
+        /// - `value`: New value/bits to be set in `base`.
+        /// - `first`: First (most significant) bit of the range, starting at
+        ///            position `0` (MSB).
+        /// - `last`: Last (least significant) bit of the range.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        #[doc = concat!("use bit_ops::bitops_", stringify!($primitive_ty), "::msb0::set_bits;")]
+        ///
+        #[doc = concat!("let last = ", stringify!($primitive_ty), "::BITS as ", stringify!($primitive_ty), " - 1;")]
+        /// assert_eq!(set_bits(0b1000, 0b101, last - 2, last), 0b1101);
+        /// ```
+        ///
+        /// # Panics
+        ///
+        /// This function panics if `first > last` and for bit positions that
+        /// are outside the range of the underlying type.
+        #[must_use]
+        #[inline]
+        pub const fn set_bits(
+            base: $primitive_ty,
+            value: $primitive_ty,
+            first: $primitive_ty,
+            last: $primitive_ty,
+        ) -> $primitive_ty {
+            let (bits, shift) = to_bits_and_shift(first, last);
+            super::set_bits(base, value, bits, shift)
+        }
+
+        /// Like [`set_bits`] but clears the relevant bits beforehand.
+        ///
+        /// # Parameters
+        ///
+        /// - `base`: Base value to alter.
+        /// - `value`: New value/bits to be set in `base`.
+        /// - `first`: First (most significant) bit of the range, starting at
+        ///            position `0` (MSB).
+        /// - `last`: Last (least significant) bit of the range.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        #[doc = concat!("use bit_ops::bitops_", stringify!($primitive_ty), "::msb0::set_bits_exact;")]
+        ///
+        #[doc = concat!("let last = ", stringify!($primitive_ty), "::BITS as ", stringify!($primitive_ty), " - 1;")]
+        /// assert_eq!(set_bits_exact(0b1111, 0b010, last - 2, last), 0b1010);
+        /// ```
+        ///
+        /// # Panics
+        ///
+        /// This function panics if `first > last` and for bit positions that
+        /// are outside the range of the underlying type.
+        #[must_use]
+        #[inline]
+        pub const fn set_bits_exact(
+            base: $primitive_ty,
+            value: $primitive_ty,
+            first: $primitive_ty,
+            last: $primitive_ty,
+        ) -> $primitive_ty {
+            let (bits, shift) = to_bits_and_shift(first, last);
+            super::set_bits_exact(base, value, bits, shift)
+        }
+
+        /// Returns the bits in the inclusive range `first..=last` as new
+        /// integer.
+        ///
+        /// # Parameters
+        ///
+        /// - `base`: Base value to get a specific set of bits from.
+        /// - `first`: First (most significant) bit of the range, starting at
+        ///            position `0` (MSB).
+        /// - `last`: Last (least significant) bit of the range.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        #[doc = concat!("use bit_ops::bitops_", stringify!($primitive_ty), "::msb0::get_bits;")]
+        ///
+        #[doc = concat!("let value = 0b1101 << (", stringify!($primitive_ty), "::BITS - 4);")]
+        /// assert_eq!(get_bits(value, 0, 2), 0b110);
+        /// assert_eq!(get_bits(value, 1, 3), 0b101);
+        /// ```
+        ///
+        /// # Panics
+        ///
+        /// This function panics if `first > last` and for bit positions that
+        /// are outside the range of the underlying type.
+        #[must_use]
+        #[inline]
+        pub const fn get_bits(
+            base: $primitive_ty,
+            first: $primitive_ty,
+            last: $primitive_ty,
+        ) -> $primitive_ty {
+            let (bits, shift) = to_bits_and_shift(first, last);
+            super::get_bits(base, bits, shift)
+        }
+
+        /// Creates a bitmask (`1`s) covering the inclusive range
+        /// `first..=last`.
+        ///
+        /// # Parameters
+        ///
+        /// - `first`: First (most significant) bit of the range, starting at
+        ///            position `0` (MSB).
+        /// - `last`: Last (least significant) bit of the range.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        #[doc = concat!("use bit_ops::bitops_", stringify!($primitive_ty), "::msb0::create_mask;")]
+        ///
+        #[doc = concat!("let last = ", stringify!($primitive_ty), "::BITS as ", stringify!($primitive_ty), " - 1;")]
+        /// assert_eq!(create_mask(last - 3, last), 0b1111);
+        #[doc = concat!("assert_eq!(create_mask(0, last), ", stringify!($primitive_ty), "::MAX);")]
+        /// ```
+        ///
+        /// # Panics
+        ///
+        /// This function panics if `first > last` and for bit positions that
+        /// are outside the range of the underlying type.
+        #[must_use]
+        #[inline]
+        pub const fn create_mask(first: $primitive_ty, last: $primitive_ty) -> $primitive_ty {
+            let (bits, shift) = to_bits_and_shift(first, last);
+            super::create_mask(bits) << shift
+        }
+
+        /// Returns the highest (most significant) bit that is set, if any.
+        ///
+        /// As bit `0` is the most significant bit, this is the set bit with
+        /// the smallest MSB-0 position.
+        ///
+        /// # Parameters
+        ///
+        /// - `base`: Base value to get highest bit from.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        #[doc = concat!("use bit_ops::bitops_", stringify!($primitive_ty), "::msb0::highest_bit;")]
+        ///
+        /// assert_eq!(highest_bit(0), None);
+        #[doc = concat!("assert_eq!(highest_bit(", stringify!($primitive_ty), "::MAX), Some(0));")]
+        #[doc = concat!("assert_eq!(highest_bit(1), Some(", stringify!($primitive_ty), "::BITS as ", stringify!($primitive_ty), " - 1));")]
+        /// ```
+        #[must_use]
+        #[inline]
+        pub const fn highest_bit(base: $primitive_ty) -> Option<$primitive_ty> {
+            match super::highest_bit(base) {
+                Some(bit) => Some(to_lsb0(bit)),
+                None => None,
+            }
+        }
+
+        /// Returns the lowest (least significant) bit that is set, if any.
+        ///
+        /// As bit `0` is the most significant bit, this is the set bit with
+        /// the largest MSB-0 position.
+        ///
+        /// # Parameters
+        ///
+        /// - `base`: Base value to get lowest bit from.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        #[doc = concat!("use bit_ops::bitops_", stringify!($primitive_ty), "::msb0::lowest_bit;")]
+        ///
+        /// assert_eq!(lowest_bit(0), None);
+        #[doc = concat!("assert_eq!(lowest_bit(", stringify!($primitive_ty), "::MAX), Some(", stringify!($primitive_ty), "::BITS as ", stringify!($primitive_ty), " - 1));")]
+        /// ```
+        #[must_use]
+        #[inline]
+        pub const fn lowest_bit(base: $primitive_ty) -> Option<$primitive_ty> {
+            match super::lowest_bit(base) {
+                Some(bit) => Some(to_lsb0(bit)),
+                None => None,
+            }
+        }
+    };
+}
+
+/// Implements the module wrapping the corresponding [`impl_bit_ops`] code.
+///
+/// If a second type with twice the amount of bits is given, the Morton /
+/// Z-order functions of `impl_morton!` are also generated.
+macro_rules! impl_mod {
+    ($primitive_ty:ident $(, $wide_ty:ty)?) => {
+        paste::paste! {
+            /// Various bit manipulation operations for the primitive type
+            #[doc = concat!("[`", stringify!($primitive_ty), "`].")]
+            ///
+            /// All functions are non-mutating but produce a new value.
+            pub mod [< bitops _ $primitive_ty >]  {
+                impl_bit_ops!($primitive_ty);
+                $(impl_morton!($primitive_ty, $wide_ty);)?
+
+                /// MSB-0 flavour of the bit operations for the primitive type
+                #[doc = concat!("[`", stringify!($primitive_ty), "`].")]
+                ///
+                /// In MSB-0 numbering, bit `0` is the most significant bit.
+                /// This is the numbering used by PowerPC, IBM mainframes, and
+                /// many IETF RFC diagrams. Ranges are specified as inclusive
+                /// `first..=last` bit positions, as they usually appear in
+                /// such specifications.
+                ///
+                /// All functions are implemented on top of the LSB-0
+                /// functions of the parent module.
+                pub mod msb0 {
+                    impl_bit_ops_msb0!($primitive_ty);
+                }
             }
         }
     };
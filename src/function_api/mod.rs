@@ -36,20 +36,36 @@ SOFTWARE.
 //! - [`bitops_u64`]
 //! - [`bitops_u128`]
 //! - [`bitops_usize`]
+//!
+//! The modules for `u8`, `u16`, `u32`, and `u64` additionally provide Morton /
+//! Z-order interleaving of coordinates into the type with twice the amount of
+//! bits (e.g., [`bitops_u32::interleave_bits`]).
+//!
+//! For integers wider than [`u128`], [`bitops_wide`] provides the essential
+//! operations on `[u64; N]`.
 
+pub mod bitops_wide;
+#[cfg(all(target_arch = "x86_64", target_feature = "bmi1"))]
+mod bmi1;
+#[cfg(all(target_arch = "x86_64", target_feature = "bmi2"))]
+mod bmi2;
 #[macro_use]
 mod macros;
+#[macro_use]
+mod morton;
 
-impl_mod!(u8);
-impl_mod!(u16);
-impl_mod!(u32);
-impl_mod!(u64);
+impl_mod!(u8, u16);
+impl_mod!(u16, u32);
+impl_mod!(u32, u64);
+impl_mod!(u64, u128);
 impl_mod!(u128);
 impl_mod!(usize);
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::format;
+    use std::vec::Vec;
 
     #[test]
     fn set_bit() {
@@ -247,6 +263,743 @@ mod tests {
         assert_eq!(bitops_u64::create_mask(64), u64::MAX);
     }
 
+    #[test]
+    fn msb0() {
+        assert_eq!(bitops_u8::msb0::set_bit(0, 0), 0b1000_0000);
+        assert_eq!(bitops_u8::msb0::set_bit(0, 7), 0b1);
+        assert_eq!(bitops_u8::msb0::clear_bit(u8::MAX, 7), 0b1111_1110);
+        assert!(bitops_u8::msb0::is_set(0b0100_0000, 1));
+        assert_eq!(bitops_u8::msb0::get_bit(0b0100_0000, 1), 1);
+        assert_eq!(bitops_u8::msb0::toggle_bit(0, 1), 0b0100_0000);
+        assert_eq!(bitops_u8::msb0::toggle_bits(0, 2, 5), 0b0011_1100);
+        assert_eq!(bitops_u8::msb0::set_bits(0, 0b11, 6, 7), 0b11);
+        assert_eq!(bitops_u8::msb0::set_bits_exact(u8::MAX, 0, 0, 3), 0xf);
+        assert_eq!(bitops_u8::msb0::get_bits(0b1011_0000, 0, 3), 0b1011);
+        assert_eq!(bitops_u8::msb0::get_bits(0b1011_0000, 0, 7), 0b1011_0000);
+        assert_eq!(bitops_u8::msb0::create_mask(0, 0), 0b1000_0000);
+        assert_eq!(bitops_u8::msb0::create_mask(0, 7), u8::MAX);
+        assert_eq!(bitops_u8::msb0::highest_bit(0b0010_0001), Some(2));
+        assert_eq!(bitops_u8::msb0::lowest_bit(0b0010_0001), Some(7));
+        assert_eq!(bitops_u8::msb0::lowest_bit(0), None);
+
+        // PowerPC: MSR[EE] is bit 48 of the 64-bit MSR (MSB-0).
+        assert_eq!(bitops_u64::msb0::set_bit(0, 48), 1 << 15);
+        // PowerPC: bits 32:63 are the lower word.
+        assert_eq!(
+            bitops_u64::msb0::get_bits(0x1234_5678_9abc_def0, 32, 63),
+            0x9abc_def0
+        );
+        assert_eq!(
+            bitops_u64::msb0::set_bits_exact(0, 0x1234_5678, 0, 31),
+            0x1234_5678_0000_0000
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn msb0_invalid_range() {
+        let _ = bitops_u8::msb0::get_bits(0, 3, 2);
+    }
+
+    #[test]
+    fn reverse_low_bits() {
+        assert_eq!(bitops_u8::reverse_low_bits(0, 0), 0);
+        assert_eq!(bitops_u8::reverse_low_bits(u8::MAX, 0), 0);
+        assert_eq!(bitops_u8::reverse_low_bits(0b1, 1), 0b1);
+        assert_eq!(bitops_u8::reverse_low_bits(0b1110, 4), 0b0111);
+        assert_eq!(bitops_u8::reverse_low_bits(0b1000_0001, 7), 0b100_0000);
+        assert_eq!(bitops_u8::reverse_low_bits(0b1000_0010, 8), 0b0100_0001);
+
+        assert_eq!(bitops_u64::reverse_low_bits(1, 64), 1 << 63);
+        assert_eq!(bitops_u64::reverse_low_bits(u64::MAX, 33), (1 << 33) - 1);
+        // CRC-16/ARC reflects its polynomial 0x8005
+        assert_eq!(bitops_u64::reverse_low_bits(0x8005, 16), 0xa001);
+    }
+
+    #[test]
+    fn reverse_bits_in_field() {
+        assert_eq!(
+            bitops_u8::reverse_bits_in_field(0b1010_1010, 0, 0),
+            0b1010_1010
+        );
+        assert_eq!(
+            bitops_u8::reverse_bits_in_field(0b1010_1010, 8, 0),
+            0b0101_0101
+        );
+        assert_eq!(
+            bitops_u8::reverse_bits_in_field(0b1010_1010, 2, 2),
+            0b1010_0110
+        );
+        assert_eq!(
+            bitops_u8::reverse_bits_in_field(0b1000_0000, 1, 7),
+            0b1000_0000
+        );
+
+        assert_eq!(
+            bitops_u64::reverse_bits_in_field(u64::MAX - 0xf0, 8, 4),
+            u64::MAX - 0xf00
+        );
+        assert_eq!(
+            bitops_u64::reverse_bits_in_field(0xf00f << 16, 16, 16),
+            0xf00f << 16
+        );
+        assert_eq!(
+            bitops_u64::reverse_bits_in_field(0x1_0000_0000_0001, 64, 0),
+            0x8000_0000_0000_8000
+        );
+    }
+
+    #[test]
+    fn bit_reverse_permutation() {
+        use std::vec::Vec;
+
+        let indices = bitops_u8::bit_reverse_permutation(0).collect::<Vec<_>>();
+        assert_eq!(&indices, &[0]);
+        let indices = bitops_u8::bit_reverse_permutation(1).collect::<Vec<_>>();
+        assert_eq!(&indices, &[0, 1]);
+        let indices = bitops_u8::bit_reverse_permutation(2).collect::<Vec<_>>();
+        assert_eq!(&indices, &[0, 2, 1, 3]);
+        let indices = bitops_u8::bit_reverse_permutation(8).collect::<Vec<_>>();
+        assert_eq!(indices.len(), 256);
+        assert_eq!(indices[1], 128);
+        assert_eq!(indices[255], 255);
+
+        let iter = bitops_u64::bit_reverse_permutation(10);
+        assert_eq!(iter.size_hint(), (1024, Some(1024)));
+        for (i, rev) in iter.enumerate() {
+            assert_eq!(bitops_u64::reverse_low_bits(rev, 10), i as u64);
+        }
+
+        let mut iter = bitops_u128::bit_reverse_permutation(128);
+        assert_eq!(iter.next(), Some(0));
+        assert_eq!(iter.next(), Some(1 << 127));
+        assert_eq!(iter.next(), Some(1 << 126));
+    }
+
+    /// Naive reference implementation for Morton codes of `dims` coordinates.
+    fn interleave_naive(coords: &[u64], bits: u32) -> u128 {
+        let mut code = 0;
+        for bit in 0..bits {
+            for (dim, coord) in coords.iter().enumerate() {
+                let bit_val = ((coord >> bit) & 1) as u128;
+                code |= bit_val << (bit as usize * coords.len() + dim);
+            }
+        }
+        code
+    }
+
+    #[test]
+    fn interleave_bits() {
+        for x in 0..=u8::MAX {
+            for y in 0..=u8::MAX {
+                let code = bitops_u8::interleave_bits(x, y);
+                assert_eq!(code as u128, interleave_naive(&[x as u64, y as u64], 8));
+                assert_eq!(bitops_u8::deinterleave_bits(code), (x, y));
+            }
+        }
+
+        let samples = [
+            0,
+            1,
+            0xdead_beef,
+            0x1234_5678_9abc_def0,
+            u64::MAX - 1,
+            u64::MAX,
+        ];
+        for x in samples {
+            for y in samples {
+                let code = bitops_u64::interleave_bits(x, y);
+                assert_eq!(code, interleave_naive(&[x, y], 64));
+                assert_eq!(bitops_u64::deinterleave_bits(code), (x, y));
+
+                let (x, y) = (x as u16, y as u16);
+                let code = bitops_u16::interleave_bits(x, y);
+                assert_eq!(code as u128, interleave_naive(&[x as u64, y as u64], 16));
+                assert_eq!(bitops_u16::deinterleave_bits(code), (x, y));
+
+                let (x, y) = (x as u32, y as u32);
+                let code = bitops_u32::interleave_bits(x, y);
+                assert_eq!(code as u128, interleave_naive(&[x as u64, y as u64], 32));
+                assert_eq!(bitops_u32::deinterleave_bits(code), (x, y));
+            }
+        }
+    }
+
+    #[test]
+    fn interleave_bits_3d() {
+        for x in 0..32 {
+            for y in 0..32 {
+                for z in 0..32 {
+                    let code = bitops_u8::interleave_bits_3d(x, y, z);
+                    assert_eq!(
+                        code as u128,
+                        interleave_naive(&[x as u64, y as u64, z as u64], 5)
+                    );
+                    assert_eq!(bitops_u8::deinterleave_bits_3d(code), (x, y, z));
+                }
+            }
+        }
+        // Bits beyond the lowest `BITS / 3` are ignored.
+        assert_eq!(
+            bitops_u8::interleave_bits_3d(0xff, 0, 0),
+            0b001_001_001_001_001
+        );
+
+        let mask = bitops_u64::create_mask(42);
+        let samples = [0, 1, 0xdead_beef, 0x1234_5678_9abc_def0, u64::MAX];
+        for x in samples {
+            for y in samples {
+                for z in samples {
+                    let code = bitops_u64::interleave_bits_3d(x, y, z);
+                    assert_eq!(code, interleave_naive(&[x, y, z], 42));
+                    assert_eq!(
+                        bitops_u64::deinterleave_bits_3d(code),
+                        (x & mask, y & mask, z & mask)
+                    );
+
+                    let (x, y, z) = (
+                        x as u32 & 0x1f_ffff,
+                        y as u32 & 0x1f_ffff,
+                        z as u32 & 0x1f_ffff,
+                    );
+                    let code = bitops_u32::interleave_bits_3d(x, y, z);
+                    assert_eq!(
+                        code as u128,
+                        interleave_naive(&[x as u64, y as u64, z as u64], 21)
+                    );
+                    assert_eq!(bitops_u32::deinterleave_bits_3d(code), (x, y, z));
+                }
+            }
+        }
+    }
+
+    #[cfg(all(target_arch = "x86_64", target_feature = "bmi2"))]
+    #[test]
+    fn interleave_bits_bmi2() {
+        let samples = [
+            0,
+            1,
+            0xdead_beef,
+            0x1234_5678_9abc_def0,
+            u64::MAX - 1,
+            u64::MAX,
+        ];
+        for x in samples {
+            for y in samples {
+                let code = bitops_u64::interleave_bits(x, y);
+                assert_eq!(bitops_u64::interleave_bits_bmi2(x, y), code);
+                assert_eq!(bitops_u64::deinterleave_bits_bmi2(code), (x, y));
+
+                let (x8, y8) = (x as u8, y as u8);
+                let code = bitops_u8::interleave_bits(x8, y8);
+                assert_eq!(bitops_u8::interleave_bits_bmi2(x8, y8), code);
+                assert_eq!(bitops_u8::deinterleave_bits_bmi2(code), (x8, y8));
+
+                for z in samples {
+                    let code = bitops_u64::interleave_bits_3d(x, y, z);
+                    assert_eq!(bitops_u64::interleave_bits_3d_bmi2(x, y, z), code);
+                    assert_eq!(
+                        bitops_u64::deinterleave_bits_3d_bmi2(code),
+                        bitops_u64::deinterleave_bits_3d(code)
+                    );
+
+                    let (x16, y16, z16) = (x as u16, y as u16, z as u16);
+                    let code = bitops_u16::interleave_bits_3d(x16, y16, z16);
+                    assert_eq!(bitops_u16::interleave_bits_3d_bmi2(x16, y16, z16), code);
+                    assert_eq!(
+                        bitops_u16::deinterleave_bits_3d_bmi2(code),
+                        bitops_u16::deinterleave_bits_3d(code)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn gray_code() {
+        fn to_gray_naive(value: u16) -> u16 {
+            let mut gray = 0;
+            for bit in 0..16 {
+                let b = (value >> bit) & 1;
+                let next = if bit == 15 {
+                    0
+                } else {
+                    (value >> (bit + 1)) & 1
+                };
+                gray |= (b ^ next) << bit;
+            }
+            gray
+        }
+
+        for value in 0..=u8::MAX {
+            let gray = bitops_u8::to_gray(value);
+            assert_eq!(gray as u16, to_gray_naive(value as u16));
+            assert_eq!(bitops_u8::from_gray(gray), value);
+        }
+        for value in 0..=u16::MAX {
+            let gray = bitops_u16::to_gray(value);
+            assert_eq!(gray, to_gray_naive(value));
+            assert_eq!(bitops_u16::from_gray(gray), value);
+            // Consecutive values differ in exactly one bit.
+            let next = bitops_u16::to_gray(value.wrapping_add(1));
+            assert_eq!((gray ^ next).count_ones(), 1);
+        }
+
+        assert_eq!(
+            bitops_u64::from_gray(bitops_u64::to_gray(u64::MAX)),
+            u64::MAX
+        );
+        assert_eq!(bitops_u128::to_gray(1 << 127), 0b11 << 126);
+        assert_eq!(bitops_u128::from_gray(0b11 << 126), 1 << 127);
+    }
+
+    #[test]
+    fn parity() {
+        fn parity_naive(value: u16, bits: u16, shift: u16) -> bool {
+            let mut parity = false;
+            for bit in shift..(shift + bits).min(16) {
+                parity ^= (value >> bit) & 1 == 1;
+            }
+            parity
+        }
+
+        for value in 0..=u8::MAX {
+            assert_eq!(bitops_u8::parity(value), parity_naive(value as u16, 8, 0));
+            for bits in 0..=8 {
+                for shift in 0..8 {
+                    assert_eq!(
+                        bitops_u8::parity_of_field(value, bits, shift),
+                        parity_naive(value as u16, bits as u16, shift as u16)
+                    );
+                }
+            }
+        }
+        for value in 0..=u16::MAX {
+            assert_eq!(bitops_u16::parity(value), parity_naive(value, 16, 0));
+            assert_eq!(
+                bitops_u16::parity_of_field(value, 5, 7),
+                parity_naive(value, 5, 7)
+            );
+        }
+
+        assert!(!bitops_u64::parity(u64::MAX));
+        assert!(bitops_u64::parity(u64::MAX - 1));
+        assert!(bitops_u64::parity_of_field(u64::MAX, 63, 1));
+    }
+
+    #[test]
+    fn count_ones_in_field() {
+        fn count_naive(value: u16, bits: u16, shift: u16) -> u16 {
+            let mut count = 0;
+            for bit in shift..(shift + bits).min(16) {
+                count += (value >> bit) & 1;
+            }
+            count
+        }
+
+        for value in 0..=u8::MAX {
+            for bits in 0..=8 {
+                for shift in 0..8 {
+                    assert_eq!(
+                        bitops_u8::count_ones_in_field(value, bits, shift) as u16,
+                        count_naive(value as u16, bits as u16, shift as u16)
+                    );
+                }
+            }
+        }
+        for value in 0..=u16::MAX {
+            assert_eq!(
+                bitops_u16::count_ones_in_field(value, 16, 0),
+                count_naive(value, 16, 0)
+            );
+            assert_eq!(
+                bitops_u16::count_ones_in_field(value, 9, 3),
+                count_naive(value, 9, 3)
+            );
+        }
+
+        assert_eq!(bitops_u64::count_ones_in_field(u64::MAX, 64, 0), 64);
+        assert_eq!(bitops_u64::count_ones_in_field(u64::MAX, 13, 51), 13);
+    }
+
+    #[test]
+    fn rank_select() {
+        for value in 0..=u8::MAX {
+            let set_bits = (0..8).filter(|&bit| (value >> bit) & 1 == 1);
+            for (n, bit) in set_bits.enumerate() {
+                assert_eq!(bitops_u8::select(value, n as u8), Some(bit));
+                assert_eq!(bitops_u8::rank(value, bit), n as u8);
+            }
+            assert_eq!(bitops_u8::select(value, value.count_ones() as u8), None);
+            assert_eq!(bitops_u8::rank(value, 8), value.count_ones() as u8);
+        }
+
+        for value in [0, 1, 0xdead_beef, 0x8000_0000_0000_0001, u64::MAX] {
+            for (n, bit) in crate::BitsIter::new(value).enumerate() {
+                assert_eq!(bitops_u64::select(value, n as u64), Some(bit));
+                assert_eq!(bitops_u64::rank(value, bit), n as u64);
+            }
+            assert_eq!(bitops_u64::select(value, value.count_ones() as u64), None);
+        }
+        assert_eq!(bitops_u128::select(u128::MAX, 127), Some(127));
+        assert_eq!(bitops_u128::select(1 << 127, 0), Some(127));
+    }
+
+    #[cfg(all(target_arch = "x86_64", target_feature = "bmi2"))]
+    #[test]
+    fn select_bmi2() {
+        for value in 0..=u16::MAX {
+            for n in 0..=16 {
+                assert_eq!(
+                    bitops_u16::select_bmi2(value, n),
+                    bitops_u16::select(value, n)
+                );
+            }
+        }
+        for value in [0xdead_beef_u128 << 70 | 0xf0f0, u128::MAX] {
+            for n in 0..=128 {
+                assert_eq!(
+                    bitops_u128::select_bmi2(value, n),
+                    bitops_u128::select(value, n)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn align() {
+        assert_eq!(bitops_u8::align_down(0xff, 8), 0);
+        assert_eq!(bitops_u8::align_up(0x01, 8), 0);
+        assert_eq!(bitops_u8::align_up(0, 8), 0);
+        assert_eq!(bitops_u8::checked_align_up(0x01, 8), None);
+        assert_eq!(bitops_u8::checked_align_up(0, 8), Some(0));
+        assert_eq!(bitops_u8::checked_align_up(0xf0, 4), Some(0xf0));
+        assert_eq!(bitops_u8::checked_align_up(0xf1, 4), None);
+        assert!(bitops_u8::is_aligned(0, 8));
+        assert!(!bitops_u8::is_aligned(0x80, 8));
+
+        for value in 0..=u8::MAX {
+            for align_bits in 0..=8 {
+                let align = 1_u16 << align_bits;
+                let value16 = value as u16;
+                let down = value16 / align * align;
+                let up = value16.div_ceil(align) * align;
+                assert_eq!(bitops_u8::align_down(value, align_bits) as u16, down);
+                assert_eq!(bitops_u8::align_up(value, align_bits), up as u8);
+                assert_eq!(
+                    bitops_u8::checked_align_up(value, align_bits),
+                    u8::try_from(up).ok()
+                );
+                assert_eq!(bitops_u8::is_aligned(value, align_bits), down == value16);
+            }
+        }
+
+        const PAGE_BITS: u64 = 12;
+        assert_eq!(bitops_u64::align_down(0x1234_5678, PAGE_BITS), 0x1234_5000);
+        assert_eq!(bitops_u64::align_up(0x1234_5678, PAGE_BITS), 0x1234_6000);
+        assert_eq!(bitops_u64::align_up(u64::MAX, PAGE_BITS), 0);
+        assert_eq!(bitops_u64::checked_align_up(u64::MAX, PAGE_BITS), None);
+        assert_eq!(bitops_u64::align_up(u64::MAX, 0), u64::MAX);
+    }
+
+    #[test]
+    fn is_power_of_two_mask() {
+        for value in 0..=u8::MAX {
+            let expected = (0..=8).any(|bits| bitops_u8::create_mask(bits) == value);
+            assert_eq!(bitops_u8::is_power_of_two_mask(value), expected);
+        }
+        assert!(bitops_u64::is_power_of_two_mask(u64::MAX));
+        assert!(!bitops_u64::is_power_of_two_mask(u64::MAX - 1));
+    }
+
+    #[test]
+    fn largest_aligned_block() {
+        for addr in 0..=u8::MAX {
+            for size in 0..=u8::MAX {
+                // naive: largest k where addr is aligned and block fits
+                let expected = (0..8)
+                    .rev()
+                    .find(|&k| addr % (1 << k) == 0 && (1_u16 << k) <= size as u16);
+                assert_eq!(bitops_u8::largest_aligned_block(addr, size), expected);
+            }
+        }
+
+        // 2 MiB huge page at a 2 MiB aligned address
+        assert_eq!(
+            bitops_u64::largest_aligned_block(0x4020_0000, 0x40_0000),
+            Some(21)
+        );
+        assert_eq!(
+            bitops_u64::largest_aligned_block(0x4020_0000, 0x1f_ffff),
+            Some(20)
+        );
+        assert_eq!(bitops_u64::largest_aligned_block(0, u64::MAX), Some(63));
+    }
+
+    #[test]
+    fn round_to_pow2() {
+        assert_eq!(bitops_u8::round_to_pow2(0), Some(1));
+        assert_eq!(bitops_u8::round_to_pow2(1), Some(1));
+        assert_eq!(bitops_u8::round_to_pow2(3), Some(4));
+        assert_eq!(bitops_u8::round_to_pow2(128), Some(128));
+        assert_eq!(bitops_u8::round_to_pow2(129), None);
+
+        assert_eq!(bitops_u64::round_to_pow2(0x1001), Some(0x2000));
+        assert_eq!(bitops_u64::round_to_pow2(1 << 63), Some(1 << 63));
+        assert_eq!(bitops_u64::round_to_pow2((1 << 63) + 1), None);
+    }
+
+    #[test]
+    fn rotate_field() {
+        // The whole value: must match the rotate functions of core.
+        for value in 0..=u8::MAX {
+            for n in 0..20 {
+                assert_eq!(
+                    bitops_u8::rotate_field_left(value, 8, 0, n),
+                    value.rotate_left(n as u32)
+                );
+                assert_eq!(
+                    bitops_u8::rotate_field_right(value, 8, 0, n),
+                    value.rotate_right(n as u32)
+                );
+            }
+        }
+
+        // Sub-fields: compare against core's rotate on the extracted field.
+        for value in 0..=u8::MAX {
+            for bits in 1..=8 {
+                for shift in 0..=(8 - bits) {
+                    for n in 0..(2 * bits) {
+                        let field = bitops_u8::get_bits(value, bits, shift);
+                        let mut expected = field;
+                        for _ in 0..n {
+                            let top = (expected >> (bits - 1)) & 1;
+                            expected = ((expected << 1) | top) & bitops_u8::create_mask(bits);
+                        }
+                        let rotated = bitops_u8::rotate_field_left(value, bits, shift, n);
+                        assert_eq!(bitops_u8::get_bits(rotated, bits, shift), expected);
+                        // The rest is unchanged.
+                        let field_mask = bitops_u8::create_mask(bits) << shift;
+                        assert_eq!(rotated & !field_mask, value & !field_mask);
+                        // Right rotation is the inverse.
+                        assert_eq!(
+                            bitops_u8::rotate_field_right(rotated, bits, shift, n),
+                            value
+                        );
+                    }
+                }
+            }
+        }
+
+        assert_eq!(bitops_u8::rotate_field_left(0xff, 0, 0, 3), 0xff);
+        assert_eq!(bitops_u8::rotate_field_right(0xff, 0, 0, 3), 0xff);
+        assert_eq!(
+            bitops_u64::rotate_field_left(0x0000_00ab_cd00_0000, 16, 24, 8),
+            0x0000_00cd_ab00_0000
+        );
+        assert_eq!(
+            bitops_u64::rotate_field_right(u64::MAX - 1, 64, 0, 1),
+            u64::MAX >> 1
+        );
+    }
+
+    #[test]
+    fn lowest_set_primitives() {
+        for value in 0..=u8::MAX {
+            let lowest = if value == 0 {
+                0
+            } else {
+                1 << value.trailing_zeros()
+            };
+            assert_eq!(bitops_u8::isolate_lowest_set(value), lowest);
+            assert_eq!(bitops_u8::clear_lowest_set(value), value & !lowest);
+            assert_eq!(
+                bitops_u8::isolate_lowest_zero(value),
+                bitops_u8::isolate_lowest_set(!value)
+            );
+            assert_eq!(
+                bitops_u8::mask_up_to_lowest_set(value),
+                if value == 0 {
+                    u8::MAX
+                } else {
+                    (lowest << 1).wrapping_sub(1)
+                }
+            );
+            let smeared = bitops_u8::smear_right(value);
+            assert!(smeared >= value);
+            assert_eq!(smeared & smeared.wrapping_add(1), 0);
+            assert_eq!(smeared.count_ones(), 8 - value.leading_zeros());
+        }
+
+        let value = 1_u128 << 100 | 1 << 70;
+        assert_eq!(bitops_u128::isolate_lowest_set(value), 1 << 70);
+        assert_eq!(bitops_u128::clear_lowest_set(value), 1 << 100);
+        assert_eq!(
+            bitops_u128::mask_up_to_lowest_set(value),
+            u128::MAX >> (127 - 70)
+        );
+        assert_eq!(bitops_u128::smear_right(value), u128::MAX >> 27);
+    }
+
+    #[cfg(all(target_arch = "x86_64", target_feature = "bmi1"))]
+    #[test]
+    fn lowest_set_primitives_bmi1() {
+        for value in 0..=u16::MAX {
+            assert_eq!(
+                bitops_u16::isolate_lowest_set_bmi1(value),
+                bitops_u16::isolate_lowest_set(value)
+            );
+            assert_eq!(
+                bitops_u16::clear_lowest_set_bmi1(value),
+                bitops_u16::clear_lowest_set(value)
+            );
+            assert_eq!(
+                bitops_u16::mask_up_to_lowest_set_bmi1(value),
+                bitops_u16::mask_up_to_lowest_set(value)
+            );
+        }
+        for value in [0, 1, 1 << 63, 1 << 64, 3 << 90, u128::MAX, 1 << 127] {
+            assert_eq!(
+                bitops_u128::isolate_lowest_set_bmi1(value),
+                bitops_u128::isolate_lowest_set(value)
+            );
+            assert_eq!(
+                bitops_u128::clear_lowest_set_bmi1(value),
+                bitops_u128::clear_lowest_set(value)
+            );
+            assert_eq!(
+                bitops_u128::mask_up_to_lowest_set_bmi1(value),
+                bitops_u128::mask_up_to_lowest_set(value)
+            );
+        }
+    }
+
+    #[test]
+    fn next_same_popcount() {
+        for ones in 1..=8 {
+            let mut expected = (0..=u8::MAX).filter(|v| v.count_ones() == ones);
+            let mut value = expected.next();
+            while let Some(v) = value {
+                let next = bitops_u8::next_same_popcount(v);
+                assert_eq!(next, expected.next());
+                value = next;
+            }
+        }
+        assert_eq!(bitops_u8::next_same_popcount(0), None);
+        assert_eq!(bitops_u64::next_same_popcount(1 << 62), Some(1 << 63));
+        assert_eq!(bitops_u64::next_same_popcount(1 << 63), None);
+    }
+
+    #[test]
+    fn scan_bits() {
+        for value in 0..=u8::MAX {
+            for from in 0..=8_u8 {
+                let is_set = |bit: &u8| value & (1 << bit) != 0;
+                assert_eq!(bitops_u8::next_set_bit(value, from), (from..8).find(is_set));
+                assert_eq!(
+                    bitops_u8::prev_set_bit(value, from),
+                    (0..from).rev().find(is_set)
+                );
+                assert_eq!(
+                    bitops_u8::next_clear_bit(value, from),
+                    (from..8).find(|bit| !is_set(bit))
+                );
+                assert_eq!(
+                    bitops_u8::prev_clear_bit(value, from),
+                    (0..from).rev().find(|bit| !is_set(bit))
+                );
+            }
+        }
+
+        assert_eq!(bitops_u128::next_set_bit(1 << 127, 100), Some(127));
+        assert_eq!(bitops_u128::prev_set_bit(1 << 127, 128), Some(127));
+        assert_eq!(bitops_u128::prev_set_bit(1 << 127, 127), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn scan_bits_out_of_range() {
+        let _ = bitops_u8::next_set_bit(0, 9);
+    }
+
+    #[test]
+    fn runs_of_ones() {
+        for value in 0..=u8::MAX {
+            let runs = (0..8_u8)
+                .filter(|&bit| bitops_u8::run_of_ones_at(value, bit) > 0)
+                .filter(|&bit| bit == 0 || value & (1 << (bit - 1)) == 0)
+                .map(|bit| (bit, bitops_u8::run_of_ones_at(value, bit)))
+                .collect::<Vec<_>>();
+            assert_eq!(bitops_u8::count_runs(value), runs.len() as u8);
+
+            let longest = runs
+                .iter()
+                .copied()
+                .fold((0, 0), |acc, run| if run.1 > acc.1 { run } else { acc });
+            assert_eq!(bitops_u8::longest_run_of_ones(value), longest);
+
+            for n in 0..=9_u8 {
+                let expected = (0..=8_u8).find(|&pos| {
+                    n == 0 || (pos + n <= 8 && (pos..pos + n).all(|bit| value & (1 << bit) == 0))
+                });
+                let expected = if n == 0 { Some(0) } else { expected };
+                assert_eq!(
+                    bitops_u8::first_run_of_zeros_with_len(value, n),
+                    expected,
+                    "value={value:#b}, n={n}"
+                );
+            }
+        }
+
+        // 32 hardware queues, the lower 20 and queue 25 are in use.
+        let used = 0x020f_ffff_u32;
+        assert_eq!(bitops_u32::first_run_of_zeros_with_len(used, 4), Some(20));
+        assert_eq!(bitops_u32::first_run_of_zeros_with_len(used, 6), Some(26));
+        assert_eq!(bitops_u32::first_run_of_zeros_with_len(used, 7), None);
+        assert_eq!(bitops_u32::longest_run_of_ones(used), (0, 20));
+        assert_eq!(bitops_u128::longest_run_of_ones(u128::MAX << 1), (1, 127));
+    }
+
+    #[test]
+    fn parse_pattern() {
+        assert_eq!(bitops_u8::parse_pattern("0000_0000"), (0xff, 0));
+        assert_eq!(bitops_u8::parse_pattern("11111111"), (0xff, 0xff));
+        assert_eq!(bitops_u8::parse_pattern("xXx._.xx."), (0, 0));
+        assert_eq!(
+            bitops_u16::parse_pattern("1xxx_xxxx_xxxx_xxx0"),
+            (0x8001, 0x8000)
+        );
+        let (mask, value) = bitops_u128::parse_pattern(&format!("1{:0127}", 0));
+        assert_eq!((mask, value), (u128::MAX, 1 << 127));
+
+        for base in 0..=u8::MAX {
+            let matches = bitops_u8::matches_pattern(
+                base,
+                bitops_u8::pattern_mask("xxxx_01x1"),
+                bitops_u8::pattern_value("xxxx_01x1"),
+            );
+            assert_eq!(matches, base & 0b1101 == 0b0101);
+        }
+    }
+
+    #[test]
+    #[should_panic = "invalid character"]
+    fn parse_pattern_invalid_character() {
+        let _ = bitops_u8::parse_pattern("0000_0002");
+    }
+
+    #[test]
+    #[should_panic = "more bits"]
+    fn parse_pattern_too_long() {
+        let _ = bitops_u8::parse_pattern("0000_00000");
+    }
+
+    #[test]
+    #[should_panic = "fewer bits"]
+    fn parse_pattern_too_short() {
+        let _ = bitops_u8::parse_pattern("0000_000");
+    }
+
     /// This tests various functions in combination using a real-world scenario.
     #[test]
     fn combined() {
@@ -0,0 +1,292 @@
+//! Morton / Z-order interleaving of coordinates for the Function API.
+//!
+//! The bits are spread and compacted with the classic "magic number" approach:
+//! In each step, a block of bits is moved by a shift and masked afterward. The
+//! masks (magic numbers) are computed at compile time by [`spread_mask`] rather
+//! than being spelled out for every width.
+//!
+//! If the `bmi2` target feature is enabled on `x86_64`, additional non-`const`
+//! variants using `pdep`/`pext` are available.
+
+/// Returns the mask of all bit positions that are occupied after a spreading
+/// step with the given block size.
+///
+/// After the step, each block of `block` consecutive bits of the source value
+/// starts at `dims * block * j`, where `j` is the index of the block.
+///
+/// # Parameters
+///
+/// - `dims`: Number of dimensions, i.e., the distance between two bits of the
+///   same coordinate in the final result.
+/// - `block`: Block size of the spreading step (power of two).
+/// - `bits`: Amount of bits of each coordinate.
+pub(super) const fn spread_mask(dims: u32, block: u32, bits: u32) -> u128 {
+    let mut mask = 0;
+    let mut i = 0;
+    while i < bits {
+        mask |= 1 << (dims * (i - i % block) + i % block);
+        i += 1;
+    }
+    mask
+}
+
+/// Returns the masks for all steps of the spreading and compacting for the
+/// given parameters, indexed by `log2(block)`.
+pub(super) const fn spread_masks(dims: u32, bits: u32) -> [u128; 7] {
+    let mut masks = [0; 7];
+    let mut k = 0;
+    while k < masks.len() {
+        masks[k] = spread_mask(dims, 1 << k, bits);
+        k += 1;
+    }
+    masks
+}
+
+/// Implements the Morton / Z-order interleaving for the specified primitive
+/// type, where `$wide_ty` is the type with twice the amount of bits.
+macro_rules! impl_morton {
+    ($primitive_ty:ty, $wide_ty:ty) => {
+        /// Amount of bits of each coordinate in the 3-D functions.
+        const MORTON_3D_BITS: u32 = <$wide_ty>::BITS / 3;
+
+        /// Masks for spreading/compacting two coordinates.
+        const MORTON_2D_MASKS: [$wide_ty; 7] = {
+            let masks = super::morton::spread_masks(2, <$primitive_ty>::BITS);
+            let mut wide_masks = [0; 7];
+            let mut k = 0;
+            while k < masks.len() {
+                wide_masks[k] = masks[k] as $wide_ty;
+                k += 1;
+            }
+            wide_masks
+        };
+
+        /// Masks for spreading/compacting three coordinates.
+        const MORTON_3D_MASKS: [$wide_ty; 7] = {
+            let masks = super::morton::spread_masks(3, MORTON_3D_BITS);
+            let mut wide_masks = [0; 7];
+            let mut k = 0;
+            while k < masks.len() {
+                wide_masks[k] = masks[k] as $wide_ty;
+                k += 1;
+            }
+            wide_masks
+        };
+
+        /// Spreads the lowest `bits` bits of `value` so that there are
+        /// `dims - 1` zero bits between each of them.
+        #[inline]
+        const fn morton_spread(
+            value: $wide_ty,
+            dims: u32,
+            bits: u32,
+            masks: &[$wide_ty; 7],
+        ) -> $wide_ty {
+            let mut value = value & masks[6];
+            let mut block = bits.next_power_of_two() / 2;
+            while block > 0 {
+                value = (value | (value << ((dims - 1) * block)))
+                    & masks[block.trailing_zeros() as usize];
+                block /= 2;
+            }
+            value
+        }
+
+        /// Inverse of [`morton_spread`].
+        #[inline]
+        const fn morton_compact(
+            value: $wide_ty,
+            dims: u32,
+            bits: u32,
+            masks: &[$wide_ty; 7],
+        ) -> $wide_ty {
+            let mut value = value & masks[0];
+            let mut block = 1;
+            while block < bits {
+                value = (value | (value >> ((dims - 1) * block)))
+                    & masks[(block * 2).trailing_zeros() as usize];
+                block *= 2;
+            }
+            value
+        }
+
+        /// Interleaves the bits of two coordinates into a Morton (Z-order)
+        /// code.
+        ///
+        /// Bit `i` of `x` ends up at bit `2i` and bit `i` of `y` at bit
+        /// `2i + 1` of the result.
+        ///
+        /// # Parameters
+        ///
+        /// - `x`: First coordinate (even bits).
+        /// - `y`: Second coordinate (odd bits).
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        #[doc = concat!("use bit_ops::bitops_", stringify!($primitive_ty), "::interleave_bits;")]
+        ///
+        /// assert_eq!(interleave_bits(0b11, 0b00), 0b0101);
+        /// assert_eq!(interleave_bits(0b00, 0b11), 0b1010);
+        /// assert_eq!(interleave_bits(0b101, 0b011), 0b01_10_11);
+        #[doc = concat!("assert_eq!(interleave_bits(", stringify!($primitive_ty), "::MAX, ", stringify!($primitive_ty), "::MAX), ", stringify!($wide_ty), "::MAX);")]
+        /// ```
+        #[must_use]
+        #[inline]
+        pub const fn interleave_bits(x: $primitive_ty, y: $primitive_ty) -> $wide_ty {
+            const BITS: u32 = <$primitive_ty>::BITS;
+            let x = morton_spread(x as $wide_ty, 2, BITS, &MORTON_2D_MASKS);
+            let y = morton_spread(y as $wide_ty, 2, BITS, &MORTON_2D_MASKS);
+            x | (y << 1)
+        }
+
+        /// Splits a Morton (Z-order) code into its two coordinates.
+        ///
+        /// This is the inverse of [`interleave_bits`].
+        ///
+        /// # Parameters
+        ///
+        /// - `z`: Morton code.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        #[doc = concat!("use bit_ops::bitops_", stringify!($primitive_ty), "::{deinterleave_bits, interleave_bits};")]
+        ///
+        /// assert_eq!(deinterleave_bits(0b01_10_11), (0b101, 0b011));
+        /// assert_eq!(deinterleave_bits(interleave_bits(42, 7)), (42, 7));
+        /// ```
+        #[must_use]
+        #[inline]
+        pub const fn deinterleave_bits(z: $wide_ty) -> ($primitive_ty, $primitive_ty) {
+            const BITS: u32 = <$primitive_ty>::BITS;
+            let x = morton_compact(z, 2, BITS, &MORTON_2D_MASKS);
+            let y = morton_compact(z >> 1, 2, BITS, &MORTON_2D_MASKS);
+            (x as $primitive_ty, y as $primitive_ty)
+        }
+
+        /// Interleaves the bits of three coordinates into a 3-D Morton
+        /// (Z-order) code.
+        ///
+        /// Bit `i` of `x`, `y`, and `z` ends up at bit `3i`, `3i + 1`, and
+        /// `3i + 2` of the result, respectively.
+        #[doc = concat!("As the result is a [`", stringify!($wide_ty), "`], only the lowest `", stringify!($wide_ty), "::BITS / 3`")]
+        /// bits of each coordinate are used. All higher bits are ignored.
+        ///
+        /// # Parameters
+        ///
+        /// - `x`: First coordinate.
+        /// - `y`: Second coordinate.
+        /// - `z`: Third coordinate.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        #[doc = concat!("use bit_ops::bitops_", stringify!($primitive_ty), "::interleave_bits_3d;")]
+        ///
+        /// assert_eq!(interleave_bits_3d(0b1, 0b0, 0b0), 0b001);
+        /// assert_eq!(interleave_bits_3d(0b0, 0b1, 0b0), 0b010);
+        /// assert_eq!(interleave_bits_3d(0b0, 0b0, 0b1), 0b100);
+        /// assert_eq!(interleave_bits_3d(0b10, 0b11, 0b01), 0b011_110);
+        /// ```
+        #[must_use]
+        #[inline]
+        pub const fn interleave_bits_3d(
+            x: $primitive_ty,
+            y: $primitive_ty,
+            z: $primitive_ty,
+        ) -> $wide_ty {
+            let x = morton_spread(x as $wide_ty, 3, MORTON_3D_BITS, &MORTON_3D_MASKS);
+            let y = morton_spread(y as $wide_ty, 3, MORTON_3D_BITS, &MORTON_3D_MASKS);
+            let z = morton_spread(z as $wide_ty, 3, MORTON_3D_BITS, &MORTON_3D_MASKS);
+            x | (y << 1) | (z << 2)
+        }
+
+        /// Splits a 3-D Morton (Z-order) code into its three coordinates.
+        ///
+        /// This is the inverse of [`interleave_bits_3d`].
+        ///
+        /// # Parameters
+        ///
+        /// - `code`: Morton code.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        #[doc = concat!("use bit_ops::bitops_", stringify!($primitive_ty), "::{deinterleave_bits_3d, interleave_bits_3d};")]
+        ///
+        /// assert_eq!(deinterleave_bits_3d(0b011_110), (0b10, 0b11, 0b01));
+        /// assert_eq!(deinterleave_bits_3d(interleave_bits_3d(3, 1, 4)), (3, 1, 4));
+        /// ```
+        #[must_use]
+        #[inline]
+        pub const fn deinterleave_bits_3d(
+            code: $wide_ty,
+        ) -> ($primitive_ty, $primitive_ty, $primitive_ty) {
+            let x = morton_compact(code, 3, MORTON_3D_BITS, &MORTON_3D_MASKS);
+            let y = morton_compact(code >> 1, 3, MORTON_3D_BITS, &MORTON_3D_MASKS);
+            let z = morton_compact(code >> 2, 3, MORTON_3D_BITS, &MORTON_3D_MASKS);
+            (x as $primitive_ty, y as $primitive_ty, z as $primitive_ty)
+        }
+
+        /// Like [`interleave_bits`] but uses the `pdep` instruction.
+        ///
+        /// This is not `const` as the corresponding intrinsic is not.
+        #[cfg(all(target_arch = "x86_64", target_feature = "bmi2"))]
+        #[must_use]
+        #[inline]
+        pub fn interleave_bits_bmi2(x: $primitive_ty, y: $primitive_ty) -> $wide_ty {
+            let mask = MORTON_2D_MASKS[0] as u128;
+            let x = super::bmi2::pdep(x as u128, mask);
+            let y = super::bmi2::pdep(y as u128, mask);
+            (x | (y << 1)) as $wide_ty
+        }
+
+        /// Like [`deinterleave_bits`] but uses the `pext` instruction.
+        ///
+        /// This is not `const` as the corresponding intrinsic is not.
+        #[cfg(all(target_arch = "x86_64", target_feature = "bmi2"))]
+        #[must_use]
+        #[inline]
+        pub fn deinterleave_bits_bmi2(z: $wide_ty) -> ($primitive_ty, $primitive_ty) {
+            let mask = MORTON_2D_MASKS[0] as u128;
+            let x = super::bmi2::pext(z as u128, mask);
+            let y = super::bmi2::pext(z as u128, mask << 1);
+            (x as $primitive_ty, y as $primitive_ty)
+        }
+
+        /// Like [`interleave_bits_3d`] but uses the `pdep` instruction.
+        ///
+        /// This is not `const` as the corresponding intrinsic is not.
+        #[cfg(all(target_arch = "x86_64", target_feature = "bmi2"))]
+        #[must_use]
+        #[inline]
+        pub fn interleave_bits_3d_bmi2(
+            x: $primitive_ty,
+            y: $primitive_ty,
+            z: $primitive_ty,
+        ) -> $wide_ty {
+            let mask = MORTON_3D_MASKS[0] as u128;
+            let x = super::bmi2::pdep(x as u128, mask);
+            let y = super::bmi2::pdep(y as u128, mask);
+            let z = super::bmi2::pdep(z as u128, mask);
+            (x | (y << 1) | (z << 2)) as $wide_ty
+        }
+
+        /// Like [`deinterleave_bits_3d`] but uses the `pext` instruction.
+        ///
+        /// This is not `const` as the corresponding intrinsic is not.
+        #[cfg(all(target_arch = "x86_64", target_feature = "bmi2"))]
+        #[must_use]
+        #[inline]
+        pub fn deinterleave_bits_3d_bmi2(
+            code: $wide_ty,
+        ) -> ($primitive_ty, $primitive_ty, $primitive_ty) {
+            let mask = MORTON_3D_MASKS[0] as u128;
+            let x = super::bmi2::pext(code as u128, mask);
+            let y = super::bmi2::pext(code as u128, mask << 1);
+            let z = super::bmi2::pext(code as u128, mask << 2);
+            (x as $primitive_ty, y as $primitive_ty, z as $primitive_ty)
+        }
+    };
+}
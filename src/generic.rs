@@ -0,0 +1,765 @@
+//! Module providing [`BitOpsExt`], a trait for code that is generic over the
+//! width of the integer.
+//!
+//! Unlike [`BitOps`], which uses `Self` for bit positions and amounts of bits,
+//! [`BitOpsExt`] uses [`u32`] for them (like [`u64::BITS`] and
+//! [`u64::trailing_zeros`]) and provides the properties of the underlying
+//! type as associated constants. This allows writing width-generic drivers
+//! without macros:
+//!
+//! ```rust
+//! use bit_ops::generic::BitOpsExt;
+//!
+//! /// Programs the 3-bit mode field of a control register of any width.
+//! fn program<R: BitOpsExt>(reg: R, mode: R) -> R {
+//!     reg.set_bits_exact(mode, 3, R::BITS - 4).set_bit(0)
+//! }
+//!
+//! assert_eq!(program(0_u8, 0b101), 0b0101_0001);
+//! assert_eq!(program(u32::MAX, 0), 0x8fff_ffff);
+//! ```
+//!
+//! The trait is not re-exported at the crate root, as its methods have the
+//! same names as those of [`BitOps`].
+//!
+//! [`BitOps`]: crate::BitOps
+
+use crate::BitReversePermutationIter;
+use core::fmt::Debug;
+use core::hash::Hash;
+use core::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Shl, Shr};
+
+/// Common bitwise operations of the Function API for code that is generic
+/// over all unsigned integer types.
+///
+/// Each method behaves like the function of the same name in the Function
+/// API, e.g., [`bitops_u64::set_bit`], but bit positions and amounts of bits
+/// are [`u32`]. Positions that are out of range for the underlying type
+/// panic, just as in the Function API.
+///
+/// [`bitops_u64::set_bit`]: crate::bitops_u64::set_bit
+pub trait BitOpsExt:
+    Copy
+    + Default
+    + Debug
+    + Eq
+    + Ord
+    + Hash
+    + BitAnd<Output = Self>
+    + BitOr<Output = Self>
+    + BitXor<Output = Self>
+    + Not<Output = Self>
+    + Shl<u32, Output = Self>
+    + Shr<u32, Output = Self>
+    + BitAndAssign
+    + BitOrAssign
+    + BitXorAssign
+{
+    /// Number of bits of the underlying type.
+    const BITS: u32;
+    /// `0` value of the underlying type.
+    const ZERO: Self;
+    /// `1` value of the underlying type.
+    const ONE: Self;
+    /// Maximum value of the underlying type, i.e., all bits set.
+    const MAX: Self;
+
+    /// Returns the number of set bits.
+    #[must_use]
+    fn count_ones(self) -> u32;
+
+    /// Returns the number of leading (high) zero bits.
+    #[must_use]
+    fn leading_zeros(self) -> u32;
+
+    /// Returns the number of trailing (low) zero bits.
+    #[must_use]
+    fn trailing_zeros(self) -> u32;
+
+    /// Sets the given bit to `1`.
+    #[must_use]
+    fn set_bit(self, bit: u32) -> Self;
+
+    /// Sets the given bit to the given value.
+    #[must_use]
+    fn set_bit_exact(self, bit: u32, value: bool) -> Self;
+
+    /// Clears the given bit by setting it to `0`.
+    #[must_use]
+    fn clear_bit(self, bit: u32) -> Self;
+
+    /// Returns whether the given bit is set.
+    #[must_use]
+    fn is_set(self, bit: u32) -> bool;
+
+    /// Returns the integer value of the given bit (`0` or `1`).
+    #[must_use]
+    fn get_bit(self, bit: u32) -> Self;
+
+    /// Toggles (flips) the given bit.
+    #[must_use]
+    fn toggle_bit(self, bit: u32) -> Self;
+
+    /// Toggles (flips) the specified contiguous bits.
+    #[must_use]
+    fn toggle_bits(self, bits: u32, shift: u32) -> Self;
+
+    /// Sets the bits of `value` at `value_shift` without clearing the target
+    /// bits first.
+    #[must_use]
+    fn set_bits(self, value: Self, value_bits: u32, value_shift: u32) -> Self;
+
+    /// Applies [`Self::set_bits`] for each
+    /// (`value`, `value_bits`, `value_shift`) tuple in `ops`.
+    #[must_use]
+    fn set_bits_n(self, ops: &[(Self, u32, u32)]) -> Self;
+
+    /// Like [`Self::set_bits`] but clears the target bits first.
+    #[must_use]
+    fn set_bits_exact(self, value: Self, value_bits: u32, value_shift: u32) -> Self;
+
+    /// Applies [`Self::set_bits_exact`] for each
+    /// (`value`, `value_bits`, `value_shift`) tuple in `ops`.
+    #[must_use]
+    fn set_bits_exact_n(self, ops: &[(Self, u32, u32)]) -> Self;
+
+    /// Clears all bits specified in the mask by setting them to `0`.
+    #[must_use]
+    fn clear_bits(self, clear_mask: Self) -> Self;
+
+    /// Returns the highest bit that is set, if any.
+    #[must_use]
+    fn highest_bit(self) -> Option<u32>;
+
+    /// Returns the lowest bit that is set, if any.
+    #[must_use]
+    fn lowest_bit(self) -> Option<u32>;
+
+    /// Returns the requested contiguous bits as new integer.
+    #[must_use]
+    fn get_bits(self, value_bits: u32, value_shift: u32) -> Self;
+
+    /// Creates a bitmask (`1`s) with the given amount of contiguous bits.
+    #[must_use]
+    fn create_mask(bits: u32) -> Self;
+
+    /// Reverses the lowest `n` bits. All other bits of the result are `0`.
+    #[must_use]
+    fn reverse_low_bits(self, n: u32) -> Self;
+
+    /// Reverses the order of the specified contiguous bits.
+    #[must_use]
+    fn reverse_bits_in_field(self, bits: u32, shift: u32) -> Self;
+
+    /// Creates an iterator over the bit-reverse permutation of the indices
+    /// `0..2^order`.
+    #[must_use]
+    fn bit_reverse_permutation(order: u32) -> BitReversePermutationIter<Self>;
+
+    /// Converts a binary value to its (reflected binary) Gray code.
+    #[must_use]
+    fn to_gray(self) -> Self;
+
+    /// Converts a (reflected binary) Gray code back to its binary value.
+    #[must_use]
+    // Named after the Function API.
+    #[allow(clippy::wrong_self_convention)]
+    fn from_gray(self) -> Self;
+
+    /// Returns `true` if the number of set bits is odd.
+    #[must_use]
+    fn parity(self) -> bool;
+
+    /// Returns the parity of the specified contiguous bits.
+    #[must_use]
+    fn parity_of_field(self, bits: u32, shift: u32) -> bool;
+
+    /// Returns the number of set bits in the specified contiguous bits.
+    #[must_use]
+    fn count_ones_in_field(self, bits: u32, shift: u32) -> u32;
+
+    /// Returns the number of set bits below the given bit position.
+    #[must_use]
+    fn rank(self, pos: u32) -> u32;
+
+    /// Returns the position of the `n`-th set bit (starting at `0`), if any.
+    #[must_use]
+    fn select(self, n: u32) -> Option<u32>;
+
+    /// Aligns the value down to a multiple of `2^align_bits`.
+    #[must_use]
+    fn align_down(self, align_bits: u32) -> Self;
+
+    /// Aligns the value up to a multiple of `2^align_bits`.
+    #[must_use]
+    fn align_up(self, align_bits: u32) -> Self;
+
+    /// Like [`Self::align_up`] but returns `None` on overflow.
+    #[must_use]
+    fn checked_align_up(self, align_bits: u32) -> Option<Self>;
+
+    /// Returns whether the value is a multiple of `2^align_bits`.
+    #[must_use]
+    fn is_aligned(self, align_bits: u32) -> bool;
+
+    /// Returns whether the value is a mask of the form `2^n - 1`.
+    #[must_use]
+    fn is_power_of_two_mask(self) -> bool;
+
+    /// Returns the order of the largest naturally aligned block starting at
+    /// `self` that fits into `size`, if any.
+    #[must_use]
+    fn largest_aligned_block(self, size: Self) -> Option<u32>;
+
+    /// Rounds the value up to the next power of two, if it fits.
+    #[must_use]
+    fn round_to_pow2(self) -> Option<Self>;
+
+    /// Rotates the specified contiguous bits to the left by `n` bits.
+    #[must_use]
+    fn rotate_field_left(self, bits: u32, shift: u32, n: u32) -> Self;
+
+    /// Rotates the specified contiguous bits to the right by `n` bits.
+    #[must_use]
+    fn rotate_field_right(self, bits: u32, shift: u32, n: u32) -> Self;
+
+    /// Keeps only the lowest set bit.
+    #[must_use]
+    fn isolate_lowest_set(self) -> Self;
+
+    /// Clears the lowest set bit.
+    #[must_use]
+    fn clear_lowest_set(self) -> Self;
+
+    /// Sets only the bit at the position of the lowest clear bit.
+    #[must_use]
+    fn isolate_lowest_zero(self) -> Self;
+
+    /// Sets all bits below the highest set bit.
+    #[must_use]
+    fn smear_right(self) -> Self;
+
+    /// Creates a mask up to and including the lowest set bit.
+    #[must_use]
+    fn mask_up_to_lowest_set(self) -> Self;
+
+    /// Returns the next larger value with the same number of set bits, if any.
+    #[must_use]
+    fn next_same_popcount(self) -> Option<Self>;
+
+    /// Returns the lowest set bit at or above position `from`, if any.
+    #[must_use]
+    fn next_set_bit(self, from: u32) -> Option<u32>;
+
+    /// Returns the highest set bit strictly below position `from`, if any.
+    #[must_use]
+    fn prev_set_bit(self, from: u32) -> Option<u32>;
+
+    /// Returns the lowest clear bit at or above position `from`, if any.
+    #[must_use]
+    fn next_clear_bit(self, from: u32) -> Option<u32>;
+
+    /// Returns the highest clear bit strictly below position `from`, if any.
+    #[must_use]
+    fn prev_clear_bit(self, from: u32) -> Option<u32>;
+
+    /// Returns the length of the run of ones starting at position `pos`.
+    #[must_use]
+    fn run_of_ones_at(self, pos: u32) -> u32;
+
+    /// Returns the start and the length of the longest run of ones.
+    #[must_use]
+    fn longest_run_of_ones(self) -> (u32, u32);
+
+    /// Returns the position of the lowest run of at least `n` zeros, if any.
+    #[must_use]
+    fn first_run_of_zeros_with_len(self, n: u32) -> Option<u32>;
+
+    /// Returns the number of runs of ones.
+    #[must_use]
+    fn count_runs(self) -> u32;
+
+    /// Returns whether the bits selected by `mask` equal those of `value`.
+    #[must_use]
+    fn matches_pattern(self, mask: Self, value: Self) -> bool;
+}
+
+/// Clamps a bit position so that it can be converted to every unsigned type
+/// while staying out of range if it was out of range before.
+#[inline]
+const fn position(pos: u32) -> u32 {
+    if pos > u128::BITS {
+        u128::BITS + 1
+    } else {
+        pos
+    }
+}
+
+/// Reduces a rotation amount `n` modulo the field width `bits`.
+#[inline]
+const fn rotation(n: u32, bits: u32) -> u32 {
+    if bits == 0 { 0 } else { n % bits }
+}
+
+/// Implements [`BitOpsExt`] for the primitive type by forwarding all calls to
+/// the function API.
+macro_rules! impl_bit_ops_ext {
+    ($primitive_ty:ty) => {
+        impl BitOpsExt for $primitive_ty {
+            const BITS: u32 = <$primitive_ty>::BITS;
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+            const MAX: Self = <$primitive_ty>::MAX;
+
+            #[inline]
+            fn count_ones(self) -> u32 {
+                <$primitive_ty>::count_ones(self)
+            }
+
+            #[inline]
+            fn leading_zeros(self) -> u32 {
+                <$primitive_ty>::leading_zeros(self)
+            }
+
+            #[inline]
+            fn trailing_zeros(self) -> u32 {
+                <$primitive_ty>::trailing_zeros(self)
+            }
+
+            #[inline]
+            fn set_bit(self, bit: u32) -> Self {
+                paste::paste! {
+                    $crate::[< bitops _ $primitive_ty >]::set_bit(self, position(bit) as Self)
+                }
+            }
+
+            #[inline]
+            fn set_bit_exact(self, bit: u32, value: bool) -> Self {
+                paste::paste! {
+                    $crate::[< bitops _ $primitive_ty >]::set_bit_exact(self, position(bit) as Self, value)
+                }
+            }
+
+            #[inline]
+            fn clear_bit(self, bit: u32) -> Self {
+                paste::paste! {
+                    $crate::[< bitops _ $primitive_ty >]::clear_bit(self, position(bit) as Self)
+                }
+            }
+
+            #[inline]
+            fn is_set(self, bit: u32) -> bool {
+                paste::paste! {
+                    $crate::[< bitops _ $primitive_ty >]::is_set(self, position(bit) as Self)
+                }
+            }
+
+            #[inline]
+            fn get_bit(self, bit: u32) -> Self {
+                paste::paste! {
+                    $crate::[< bitops _ $primitive_ty >]::get_bit(self, position(bit) as Self)
+                }
+            }
+
+            #[inline]
+            fn toggle_bit(self, bit: u32) -> Self {
+                paste::paste! {
+                    $crate::[< bitops _ $primitive_ty >]::toggle_bit(self, position(bit) as Self)
+                }
+            }
+
+            #[inline]
+            fn toggle_bits(self, bits: u32, shift: u32) -> Self {
+                paste::paste! {
+                    $crate::[< bitops _ $primitive_ty >]::toggle_bits(self, position(bits) as Self, position(shift) as Self)
+                }
+            }
+
+            #[inline]
+            fn set_bits(self, value: Self, value_bits: u32, value_shift: u32) -> Self {
+                paste::paste! {
+                    $crate::[< bitops _ $primitive_ty >]::set_bits(self, value, position(value_bits) as Self, position(value_shift) as Self)
+                }
+            }
+
+            #[inline]
+            fn set_bits_n(self, ops: &[(Self, u32, u32)]) -> Self {
+                let mut base = self;
+                for &(value, value_bits, value_shift) in ops {
+                    base = base.set_bits(value, value_bits, value_shift);
+                }
+                base
+            }
+
+            #[inline]
+            fn set_bits_exact(self, value: Self, value_bits: u32, value_shift: u32) -> Self {
+                paste::paste! {
+                    $crate::[< bitops _ $primitive_ty >]::set_bits_exact(self, value, position(value_bits) as Self, position(value_shift) as Self)
+                }
+            }
+
+            #[inline]
+            fn set_bits_exact_n(self, ops: &[(Self, u32, u32)]) -> Self {
+                let mut base = self;
+                for &(value, value_bits, value_shift) in ops {
+                    base = base.set_bits_exact(value, value_bits, value_shift);
+                }
+                base
+            }
+
+            #[inline]
+            fn clear_bits(self, clear_mask: Self) -> Self {
+                paste::paste! {
+                    $crate::[< bitops _ $primitive_ty >]::clear_bits(self, clear_mask)
+                }
+            }
+
+            #[inline]
+            fn highest_bit(self) -> Option<u32> {
+                paste::paste! {
+                    $crate::[< bitops _ $primitive_ty >]::highest_bit(self)
+                        .map(|pos| pos as u32)
+                }
+            }
+
+            #[inline]
+            fn lowest_bit(self) -> Option<u32> {
+                paste::paste! {
+                    $crate::[< bitops _ $primitive_ty >]::lowest_bit(self)
+                        .map(|pos| pos as u32)
+                }
+            }
+
+            #[inline]
+            fn get_bits(self, value_bits: u32, value_shift: u32) -> Self {
+                paste::paste! {
+                    $crate::[< bitops _ $primitive_ty >]::get_bits(self, position(value_bits) as Self, position(value_shift) as Self)
+                }
+            }
+
+            #[inline]
+            fn create_mask(bits: u32) -> Self {
+                paste::paste! {
+                    $crate::[< bitops _ $primitive_ty >]::create_mask(position(bits) as Self)
+                }
+            }
+
+            #[inline]
+            fn reverse_low_bits(self, n: u32) -> Self {
+                paste::paste! {
+                    $crate::[< bitops _ $primitive_ty >]::reverse_low_bits(self, position(n) as Self)
+                }
+            }
+
+            #[inline]
+            fn reverse_bits_in_field(self, bits: u32, shift: u32) -> Self {
+                paste::paste! {
+                    $crate::[< bitops _ $primitive_ty >]::reverse_bits_in_field(self, position(bits) as Self, position(shift) as Self)
+                }
+            }
+
+            #[inline]
+            fn bit_reverse_permutation(order: u32) -> BitReversePermutationIter<Self> {
+                BitReversePermutationIter::<$primitive_ty>::new(position(order) as Self)
+            }
+
+            #[inline]
+            fn to_gray(self) -> Self {
+                paste::paste! {
+                    $crate::[< bitops _ $primitive_ty >]::to_gray(self)
+                }
+            }
+
+            #[inline]
+            fn from_gray(self) -> Self {
+                paste::paste! {
+                    $crate::[< bitops _ $primitive_ty >]::from_gray(self)
+                }
+            }
+
+            #[inline]
+            fn parity(self) -> bool {
+                paste::paste! {
+                    $crate::[< bitops _ $primitive_ty >]::parity(self)
+                }
+            }
+
+            #[inline]
+            fn parity_of_field(self, bits: u32, shift: u32) -> bool {
+                paste::paste! {
+                    $crate::[< bitops _ $primitive_ty >]::parity_of_field(self, position(bits) as Self, position(shift) as Self)
+                }
+            }
+
+            #[inline]
+            fn count_ones_in_field(self, bits: u32, shift: u32) -> u32 {
+                paste::paste! {
+                    $crate::[< bitops _ $primitive_ty >]::count_ones_in_field(self, position(bits) as Self, position(shift) as Self) as u32
+                }
+            }
+
+            #[inline]
+            fn rank(self, pos: u32) -> u32 {
+                paste::paste! {
+                    $crate::[< bitops _ $primitive_ty >]::rank(self, position(pos) as Self) as u32
+                }
+            }
+
+            #[inline]
+            fn select(self, n: u32) -> Option<u32> {
+                paste::paste! {
+                    $crate::[< bitops _ $primitive_ty >]::select(self, position(n) as Self)
+                        .map(|pos| pos as u32)
+                }
+            }
+
+            #[inline]
+            fn align_down(self, align_bits: u32) -> Self {
+                paste::paste! {
+                    $crate::[< bitops _ $primitive_ty >]::align_down(self, position(align_bits) as Self)
+                }
+            }
+
+            #[inline]
+            fn align_up(self, align_bits: u32) -> Self {
+                paste::paste! {
+                    $crate::[< bitops _ $primitive_ty >]::align_up(self, position(align_bits) as Self)
+                }
+            }
+
+            #[inline]
+            fn checked_align_up(self, align_bits: u32) -> Option<Self> {
+                paste::paste! {
+                    $crate::[< bitops _ $primitive_ty >]::checked_align_up(self, position(align_bits) as Self)
+                }
+            }
+
+            #[inline]
+            fn is_aligned(self, align_bits: u32) -> bool {
+                paste::paste! {
+                    $crate::[< bitops _ $primitive_ty >]::is_aligned(self, position(align_bits) as Self)
+                }
+            }
+
+            #[inline]
+            fn is_power_of_two_mask(self) -> bool {
+                paste::paste! {
+                    $crate::[< bitops _ $primitive_ty >]::is_power_of_two_mask(self)
+                }
+            }
+
+            #[inline]
+            fn largest_aligned_block(self, size: Self) -> Option<u32> {
+                paste::paste! {
+                    $crate::[< bitops _ $primitive_ty >]::largest_aligned_block(self, size)
+                        .map(|pos| pos as u32)
+                }
+            }
+
+            #[inline]
+            fn round_to_pow2(self) -> Option<Self> {
+                paste::paste! {
+                    $crate::[< bitops _ $primitive_ty >]::round_to_pow2(self)
+                }
+            }
+
+            #[inline]
+            fn rotate_field_left(self, bits: u32, shift: u32, n: u32) -> Self {
+                paste::paste! {
+                    $crate::[< bitops _ $primitive_ty >]::rotate_field_left(self, position(bits) as Self, position(shift) as Self, position(rotation(n, bits)) as Self)
+                }
+            }
+
+            #[inline]
+            fn rotate_field_right(self, bits: u32, shift: u32, n: u32) -> Self {
+                paste::paste! {
+                    $crate::[< bitops _ $primitive_ty >]::rotate_field_right(self, position(bits) as Self, position(shift) as Self, position(rotation(n, bits)) as Self)
+                }
+            }
+
+            #[inline]
+            fn isolate_lowest_set(self) -> Self {
+                paste::paste! {
+                    $crate::[< bitops _ $primitive_ty >]::isolate_lowest_set(self)
+                }
+            }
+
+            #[inline]
+            fn clear_lowest_set(self) -> Self {
+                paste::paste! {
+                    $crate::[< bitops _ $primitive_ty >]::clear_lowest_set(self)
+                }
+            }
+
+            #[inline]
+            fn isolate_lowest_zero(self) -> Self {
+                paste::paste! {
+                    $crate::[< bitops _ $primitive_ty >]::isolate_lowest_zero(self)
+                }
+            }
+
+            #[inline]
+            fn smear_right(self) -> Self {
+                paste::paste! {
+                    $crate::[< bitops _ $primitive_ty >]::smear_right(self)
+                }
+            }
+
+            #[inline]
+            fn mask_up_to_lowest_set(self) -> Self {
+                paste::paste! {
+                    $crate::[< bitops _ $primitive_ty >]::mask_up_to_lowest_set(self)
+                }
+            }
+
+            #[inline]
+            fn next_same_popcount(self) -> Option<Self> {
+                paste::paste! {
+                    $crate::[< bitops _ $primitive_ty >]::next_same_popcount(self)
+                }
+            }
+
+            #[inline]
+            fn next_set_bit(self, from: u32) -> Option<u32> {
+                paste::paste! {
+                    $crate::[< bitops _ $primitive_ty >]::next_set_bit(self, position(from) as Self)
+                        .map(|pos| pos as u32)
+                }
+            }
+
+            #[inline]
+            fn prev_set_bit(self, from: u32) -> Option<u32> {
+                paste::paste! {
+                    $crate::[< bitops _ $primitive_ty >]::prev_set_bit(self, position(from) as Self)
+                        .map(|pos| pos as u32)
+                }
+            }
+
+            #[inline]
+            fn next_clear_bit(self, from: u32) -> Option<u32> {
+                paste::paste! {
+                    $crate::[< bitops _ $primitive_ty >]::next_clear_bit(self, position(from) as Self)
+                        .map(|pos| pos as u32)
+                }
+            }
+
+            #[inline]
+            fn prev_clear_bit(self, from: u32) -> Option<u32> {
+                paste::paste! {
+                    $crate::[< bitops _ $primitive_ty >]::prev_clear_bit(self, position(from) as Self)
+                        .map(|pos| pos as u32)
+                }
+            }
+
+            #[inline]
+            fn run_of_ones_at(self, pos: u32) -> u32 {
+                paste::paste! {
+                    $crate::[< bitops _ $primitive_ty >]::run_of_ones_at(self, position(pos) as Self) as u32
+                }
+            }
+
+            #[inline]
+            fn longest_run_of_ones(self) -> (u32, u32) {
+                paste::paste! {
+                    let (start, len) = $crate::[< bitops _ $primitive_ty >]::longest_run_of_ones(self);
+                    (start as u32, len as u32)
+                }
+            }
+
+            #[inline]
+            fn first_run_of_zeros_with_len(self, n: u32) -> Option<u32> {
+                paste::paste! {
+                    $crate::[< bitops _ $primitive_ty >]::first_run_of_zeros_with_len(self, position(n) as Self)
+                        .map(|pos| pos as u32)
+                }
+            }
+
+            #[inline]
+            fn count_runs(self) -> u32 {
+                paste::paste! {
+                    $crate::[< bitops _ $primitive_ty >]::count_runs(self) as u32
+                }
+            }
+
+            #[inline]
+            fn matches_pattern(self, mask: Self, value: Self) -> bool {
+                paste::paste! {
+                    $crate::[< bitops _ $primitive_ty >]::matches_pattern(self, mask, value)
+                }
+            }
+        }
+    };
+}
+
+impl_bit_ops_ext!(u8);
+impl_bit_ops_ext!(u16);
+impl_bit_ops_ext!(u32);
+impl_bit_ops_ext!(u64);
+impl_bit_ops_ext!(u128);
+impl_bit_ops_ext!(usize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Width-generic driver exercising positions relative to `R::BITS`.
+    fn driver<R: BitOpsExt>() {
+        let top = R::BITS - 1;
+        let value = R::ZERO.set_bit(top).set_bit(1);
+        assert!(value.is_set(top));
+        assert_eq!(value.highest_bit(), Some(top));
+        assert_eq!(value.lowest_bit(), Some(1));
+        assert_eq!(value.next_set_bit(2), Some(top));
+        assert_eq!(value.prev_set_bit(R::BITS), Some(top));
+        assert_eq!(value.rank(R::BITS), 2);
+        assert_eq!(value.select(1), Some(top));
+        assert_eq!(value.clear_bit(top).toggle_bit(1), R::ZERO);
+        assert_eq!(R::create_mask(R::BITS), R::MAX);
+        assert_eq!(R::MAX.get_bits(4, R::BITS - 4), R::create_mask(4));
+        assert_eq!(R::MAX.count_ones_in_field(3, 2), 3);
+        assert_eq!(R::MAX.longest_run_of_ones(), (0, R::BITS));
+        assert_eq!(R::MAX.count_ones(), R::BITS);
+        assert_eq!(R::ONE.leading_zeros(), top);
+        assert_eq!(
+            R::ZERO.set_bits_exact_n(&[(R::MAX, 2, 0), (R::ONE, 1, top)]),
+            R::create_mask(2) | (R::ONE << top)
+        );
+        assert_eq!(
+            R::create_mask(3).rotate_field_left(4, 0, 4 * 1000 + 1),
+            R::create_mask(3).rotate_field_left(4, 0, 1)
+        );
+        assert_eq!(R::ONE.first_run_of_zeros_with_len(top), Some(1));
+        assert_eq!(R::ONE.first_run_of_zeros_with_len(R::BITS), None);
+    }
+
+    #[test]
+    fn generic_driver() {
+        driver::<u8>();
+        driver::<u16>();
+        driver::<u32>();
+        driver::<u64>();
+        driver::<u128>();
+        driver::<usize>();
+    }
+
+    #[test]
+    fn constants() {
+        assert_eq!(<u8 as BitOpsExt>::BITS, 8);
+        assert_eq!(<u128 as BitOpsExt>::MAX, u128::MAX);
+        assert_eq!(<u16 as BitOpsExt>::ONE, 1);
+        assert_eq!(<u32 as BitOpsExt>::ZERO, 0);
+    }
+
+    #[test]
+    fn bit_reverse_permutation() {
+        let indices = <u16 as BitOpsExt>::bit_reverse_permutation(3);
+        assert!(indices.eq([0, 4, 2, 6, 1, 5, 3, 7]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn out_of_range_position() {
+        // 264 would truncate to 8 when converted naively.
+        let _ = BitOpsExt::set_bit(0_u8, 264);
+    }
+}
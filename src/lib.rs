@@ -34,6 +34,7 @@ SOFTWARE.
 #![deny(missing_debug_implementations)]
 #![deny(rustdoc::all)]
 #![no_std]
+#![cfg_attr(feature = "nightly-const-trait", feature(const_trait_impl))]
 
 //! # bit_ops
 //!
@@ -53,6 +54,11 @@ SOFTWARE.
 //! next months in Rust stable. `bit_ops` will adapt, as soon as this
 //! changes.
 //!
+//! On nightly, the opt-in `nightly-const-trait` cargo feature declares
+//! [`BitOps`] as `const trait`, so that chained calls such as
+//! `0_u64.set_bit(1).set_bit(2)` work in `const` contexts. Crates using this
+//! need `#![feature(const_trait_impl)]` as well.
+//!
 //! Note that the most trivial bit operations, such as `"shift_bits"` or
 //! `"keep_bits"` won't be covered by the API, as this would introduce a
 //! convoluted way around the standard operators `<<`, `>>`, and `&`. Only
@@ -72,6 +78,13 @@ SOFTWARE.
 //! - [`bitops_u128`]
 //! - [`bitops_usize`]
 //!
+//! Each of these modules has an `msb0` submodule (e.g.
+//! [`bitops_u32::msb0`]) with the same operations but MSB-0 bit numbering,
+//! where bit `0` is the most significant bit.
+//!
+//! For integers wider than [`u128`], such as 256-bit or 512-bit registers,
+//! [`bitops_wide`] provides the essential operations on `[u64; N]`.
+//!
 //! #### Example
 //!
 //! The following example shows a real-world scenario where several properties
@@ -134,12 +147,38 @@ SOFTWARE.
 //! assert_eq!(raw, 0b110);
 //! ```
 //!
+//! [`BitOpsMsb0`] is the counterpart of the `msb0` modules of the Function
+//! API.
+//!
+//! [`BitOps`] is also implemented for [`Wrapping`] and [`Saturating`] integers.
+//! [`BitOpsNonZero`] provides a read-only subset for the non-zero integer
+//! types, e.g., with a [`BitOpsNonZero::highest_bit`] that can't fail.
+//!
+//! [`Wrapping`]: core::num::Wrapping
+//! [`Saturating`]: core::num::Saturating
+//!
 //! ### Other Functionality
 //!
 //! `bit_ops` also offers additional functionality which is tightly connected
 //! to working with raw bits:
 //!
 //! - [`BitsIter`] and [`BitmapIter`]
+//! - [`BitReversePermutationIter`]
+//! - [`PackedSlice`] and [`PackedArray`]: packed arrays of `N`-bit integers
+//! - [`PatternTable`]: decoder-style lookup of bit patterns
+//! - [`RankSelect`]: rank/select index over bitmaps
+//! - [`AlignedBlocksIter`]: decompose a range into naturally aligned
+//!   power-of-two blocks
+//! - [`BinFmt`] and [`FieldDiagram`]: pretty-print raw bits and bit fields
+//! - [`bitpack`]: bulk packing and unpacking of blocks of fixed-width integers
+//! - [`bitstream`]: bit-granular reader and writer with Exp-Golomb and Elias
+//!   codes
+//! - [`bytes`]: get and set bit fields at arbitrary bit offsets in byte slices
+//! - [`crc`]: generic CRC engine for bit fields and bytes
+//! - [`ecc`]: Hamming(7,4), SECDED(72,64), and generic parity-check syndromes
+//! - [`generic`]: [`generic::BitOpsExt`] for code that is generic over the
+//!   integer width
+//! - [`varint`]: LEB128, zigzag, and prefix varint encodings
 //!
 //! ## Comparison to other Crates
 //!
@@ -158,10 +197,29 @@ SOFTWARE.
 #[cfg(test)]
 extern crate std;
 
+mod aligned_blocks;
+mod bin_fmt;
+mod bit_reverse;
+pub mod bitpack;
 mod bitpos_iter;
+pub mod bitstream;
+pub mod bytes;
+pub mod crc;
+pub mod ecc;
 mod function_api;
+pub mod generic;
+mod packed;
+mod pattern_table;
+mod rank_select;
 mod trait_api;
+pub mod varint;
 
+pub use aligned_blocks::*;
+pub use bin_fmt::*;
+pub use bit_reverse::*;
 pub use bitpos_iter::*;
 pub use function_api::*;
+pub use packed::*;
+pub use pattern_table::*;
+pub use rank_select::*;
 pub use trait_api::*;
@@ -0,0 +1,437 @@
+//! Module providing packed arrays of `BITS`-bit integers.
+//!
+//! See [`PackedSlice`] and [`PackedArray`].
+//!
+//! Bulk packing and unpacking use the streaming kernels of the [`bitpack`]
+//! module. Values with more than `BITS` bits are rejected with a panic rather
+//! than silently truncated, both by the single-element setters and by the
+//! bulk operations.
+
+use crate::{bitops_u64, bitpack};
+use core::iter::FusedIterator;
+
+/// Returns the element at `index` of the packed `words`.
+#[inline]
+const fn get<const BITS: u32>(words: &[u64], index: usize) -> u32 {
+    let bit = index * BITS as usize;
+    let word = bit / 64;
+    let shift = (bit % 64) as u64;
+    let bits = BITS as u64;
+    if shift + bits <= 64 {
+        bitops_u64::get_bits(words[word], bits, shift) as u32
+    } else {
+        // The element straddles the word boundary.
+        let low_bits = 64 - shift;
+        let low = bitops_u64::get_bits(words[word], low_bits, shift);
+        let high = bitops_u64::get_bits(words[word + 1], bits - low_bits, 0);
+        (low | (high << low_bits)) as u32
+    }
+}
+
+/// Sets the element at `index` of the packed `words` to `value`.
+#[inline]
+const fn set<const BITS: u32>(words: &mut [u64], index: usize, value: u32) {
+    assert!(
+        value as u64 <= bitops_u64::create_mask(BITS as u64),
+        "value has more bits than the width"
+    );
+    let bit = index * BITS as usize;
+    let word = bit / 64;
+    let shift = (bit % 64) as u64;
+    let bits = BITS as u64;
+    let value = value as u64;
+    if shift + bits <= 64 {
+        words[word] = bitops_u64::set_bits_exact(words[word], value, bits, shift);
+    } else {
+        // The element straddles the word boundary.
+        let low_bits = 64 - shift;
+        let low = bitops_u64::get_bits(value, low_bits, 0);
+        words[word] = bitops_u64::set_bits_exact(words[word], low, low_bits, shift);
+        words[word + 1] =
+            bitops_u64::set_bits_exact(words[word + 1], value >> low_bits, bits - low_bits, 0);
+    }
+}
+
+/// View of a slice of [`u64`] words as a packed array of `BITS`-bit integers.
+///
+/// Element `i` occupies the bits `i * BITS..(i + 1) * BITS`, where the bits of
+/// the words are numbered as in one gigantic integer (see [`BitmapIter`]).
+/// Elements may straddle word boundaries. Supported widths are `1..=32`.
+///
+/// For an owned variant, see [`PackedArray`].
+///
+/// # Example
+/// ```rust
+/// use bit_ops::PackedSlice;
+///
+/// let mut words = [0; PackedSlice::<5>::words_len(100)];
+/// let mut slice = PackedSlice::<5>::new(&mut words, 100);
+/// slice.set(12, 0b10101);
+/// assert_eq!(slice.get(12), 0b10101);
+///
+/// let values = (0..100).map(|i| i % 32).collect::<Vec<_>>();
+/// slice.pack_from(&values);
+/// assert!(slice.iter().eq(values.iter().copied()));
+///
+/// let mut unpacked = [0; 100];
+/// slice.unpack_into(&mut unpacked);
+/// assert_eq!(&unpacked[..], &values[..]);
+/// ```
+///
+/// [`BitmapIter`]: crate::BitmapIter
+#[derive(Debug)]
+pub struct PackedSlice<'a, const BITS: u32> {
+    words: &'a mut [u64],
+    len: usize,
+}
+
+impl<'a, const BITS: u32> PackedSlice<'a, BITS> {
+    /// Returns the number of [`u64`] words required for `len` elements.
+    #[must_use]
+    pub const fn words_len(len: usize) -> usize {
+        (len * BITS as usize).div_ceil(64)
+    }
+
+    /// Creates a new view of `len` elements over the given words.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `words` is too small or if `BITS` is not in
+    /// range `1..=32`.
+    #[must_use]
+    pub const fn new(words: &'a mut [u64], len: usize) -> Self {
+        const { bitpack::assert_bits::<BITS>() };
+        assert!(
+            words.len() >= Self::words_len(len),
+            "storage for elements is too small"
+        );
+        Self { words, len }
+    }
+
+    /// Returns the number of elements.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether there are no elements.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the element at `index`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `index` is out of range.
+    #[must_use]
+    pub const fn get(&self, index: usize) -> u32 {
+        assert!(index < self.len, "index is out of range");
+        get::<BITS>(self.words, index)
+    }
+
+    /// Sets the element at `index` to `value`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `index` is out of range or if `value` has
+    /// more than `BITS` bits.
+    pub const fn set(&mut self, index: usize, value: u32) {
+        assert!(index < self.len, "index is out of range");
+        set::<BITS>(self.words, index, value);
+    }
+
+    /// Returns an iterator over all elements.
+    #[must_use]
+    pub const fn iter(&self) -> PackedIter<'_, BITS> {
+        PackedIter::new(self.words, self.len)
+    }
+
+    /// Sets the first `values.len()` elements to `values`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if there are more values than elements or if a
+    /// value has more than `BITS` bits.
+    pub fn pack_from(&mut self, values: &[u32]) {
+        assert!(values.len() <= self.len, "too many values");
+        bitpack::pack_stream::<BITS>(values, self.words);
+    }
+
+    /// Writes the first `values.len()` elements into `values`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `values` is longer than the amount of
+    /// elements.
+    pub fn unpack_into(&self, values: &mut [u32]) {
+        assert!(values.len() <= self.len, "too many values");
+        bitpack::unpack_stream::<BITS>(self.words, values);
+    }
+}
+
+/// Packed array of `BITS`-bit integers, backed by `WORDS` [`u64`] words.
+///
+/// This is the owned variant of [`PackedSlice`] with the same layout. The
+/// array holds [`PackedArray::LEN`] elements, i.e., as many as fit into the
+/// words. Supported widths are `1..=32`.
+///
+/// # Example
+/// ```rust
+/// use bit_ops::PackedArray;
+///
+/// // 64 12-bit values
+/// let mut array = PackedArray::<12, 12>::new();
+/// assert_eq!(array.len(), 64);
+/// array.set(5, 0xabc);
+/// assert_eq!(array.get(5), 0xabc);
+/// assert_eq!(array.iter().filter(|&v| v != 0).count(), 1);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PackedArray<const BITS: u32, const WORDS: usize> {
+    words: [u64; WORDS],
+}
+
+impl<const BITS: u32, const WORDS: usize> PackedArray<BITS, WORDS> {
+    /// The number of elements.
+    pub const LEN: usize = WORDS * 64 / BITS as usize;
+
+    /// Creates a new array with all elements set to `0`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `BITS` is not in range `1..=32`.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self::from_words([0; WORDS])
+    }
+
+    /// Creates a new array from its raw words.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `BITS` is not in range `1..=32`.
+    #[must_use]
+    pub const fn from_words(words: [u64; WORDS]) -> Self {
+        const { bitpack::assert_bits::<BITS>() };
+        Self { words }
+    }
+
+    /// Returns the raw words.
+    #[must_use]
+    pub const fn words(&self) -> &[u64; WORDS] {
+        &self.words
+    }
+
+    /// Returns the number of elements.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        Self::LEN
+    }
+
+    /// Returns whether there are no elements.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        Self::LEN == 0
+    }
+
+    /// Returns the element at `index`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `index` is out of range.
+    #[must_use]
+    pub const fn get(&self, index: usize) -> u32 {
+        assert!(index < Self::LEN, "index is out of range");
+        get::<BITS>(&self.words, index)
+    }
+
+    /// Sets the element at `index` to `value`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `index` is out of range or if `value` has
+    /// more than `BITS` bits.
+    pub const fn set(&mut self, index: usize, value: u32) {
+        assert!(index < Self::LEN, "index is out of range");
+        set::<BITS>(&mut self.words, index, value);
+    }
+
+    /// Returns an iterator over all elements.
+    #[must_use]
+    pub const fn iter(&self) -> PackedIter<'_, BITS> {
+        PackedIter::new(&self.words, Self::LEN)
+    }
+
+    /// Returns a [`PackedSlice`] view of the array.
+    #[must_use]
+    pub const fn as_packed_slice(&mut self) -> PackedSlice<'_, BITS> {
+        PackedSlice::new(&mut self.words, Self::LEN)
+    }
+
+    /// Sets the first `values.len()` elements to `values`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if there are more values than elements or if a
+    /// value has more than `BITS` bits.
+    pub fn pack_from(&mut self, values: &[u32]) {
+        self.as_packed_slice().pack_from(values);
+    }
+
+    /// Writes the first `values.len()` elements into `values`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `values` is longer than the amount of
+    /// elements.
+    pub fn unpack_into(&self, values: &mut [u32]) {
+        assert!(values.len() <= Self::LEN, "too many values");
+        bitpack::unpack_stream::<BITS>(&self.words, values);
+    }
+}
+
+impl<const BITS: u32, const WORDS: usize> Default for PackedArray<BITS, WORDS> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Iterator over the elements of a [`PackedSlice`] or [`PackedArray`].
+#[derive(Debug, Clone)]
+pub struct PackedIter<'a, const BITS: u32> {
+    words: &'a [u64],
+    index: usize,
+    len: usize,
+}
+
+impl<'a, const BITS: u32> PackedIter<'a, BITS> {
+    const fn new(words: &'a [u64], len: usize) -> Self {
+        Self {
+            words,
+            index: 0,
+            len,
+        }
+    }
+}
+
+impl<const BITS: u32> Iterator for PackedIter<'_, BITS> {
+    type Item = u32;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index == self.len {
+            return None;
+        }
+        let value = get::<BITS>(self.words, self.index);
+        self.index += 1;
+        Some(value)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.len - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<const BITS: u32> ExactSizeIterator for PackedIter<'_, BITS> {}
+
+impl<const BITS: u32> FusedIterator for PackedIter<'_, BITS> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::vec::Vec;
+
+    /// Pseudo-random values with the given amount of bits.
+    fn values(n: usize, bits: u32) -> Vec<u32> {
+        let mut state = 0x9e37_79b9_u32;
+        (0..n)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                (u64::from(state) & bitops_u64::create_mask(u64::from(bits))) as u32
+            })
+            .collect()
+    }
+
+    fn roundtrip<const BITS: u32>() {
+        const N: usize = 150;
+        let expected = values(N, BITS);
+
+        // Element-wise
+        let mut words = [0; PackedSlice::<32>::words_len(N)];
+        let mut slice = PackedSlice::<BITS>::new(&mut words, N);
+        for (i, &value) in expected.iter().enumerate() {
+            slice.set(i, value);
+        }
+        for (i, &value) in expected.iter().enumerate() {
+            assert_eq!(slice.get(i), value, "BITS={BITS}, i={i}");
+        }
+        assert!(slice.iter().eq(expected.iter().copied()));
+        assert_eq!(slice.iter().len(), N);
+
+        // Bulk
+        let mut bulk_words = [0; PackedSlice::<32>::words_len(N)];
+        let mut bulk = PackedSlice::<BITS>::new(&mut bulk_words, N);
+        bulk.pack_from(&expected);
+        let mut unpacked = [0; N];
+        bulk.unpack_into(&mut unpacked);
+        assert_eq!(&unpacked[..], &expected[..], "BITS={BITS}");
+        let used = PackedSlice::<BITS>::words_len(N);
+        assert_eq!(&bulk_words[..used], &words[..used], "BITS={BITS}");
+
+        // Partial bulk operations keep the remaining elements.
+        let mut slice = PackedSlice::<BITS>::new(&mut words, N);
+        slice.pack_from(&[0; 7]);
+        assert!(slice.iter().take(7).all(|value| value == 0));
+        assert!(slice.iter().skip(7).eq(expected.iter().skip(7).copied()));
+        let mut unpacked = [0; 9];
+        slice.unpack_into(&mut unpacked);
+        assert_eq!(&unpacked[7..], &expected[7..9]);
+    }
+
+    #[test]
+    fn packed_slice() {
+        roundtrip::<1>();
+        roundtrip::<3>();
+        roundtrip::<5>();
+        roundtrip::<7>();
+        roundtrip::<8>();
+        roundtrip::<12>();
+        roundtrip::<17>();
+        roundtrip::<31>();
+        roundtrip::<32>();
+    }
+
+    #[test]
+    fn packed_array() {
+        let mut array = PackedArray::<5, 3>::default();
+        assert_eq!(array.len(), 38);
+        let expected = values(38, 5);
+        array.pack_from(&expected);
+        assert!(array.iter().eq(expected.iter().copied()));
+        array.set(37, 0b11111);
+        assert_eq!(array.get(37), 0b11111);
+        // The element straddles the first and the second word.
+        array.set(12, 0b10110);
+        assert_eq!(array.words()[0] >> 60, 0b0110);
+        assert_eq!(array.words()[1] & 1, 0b1);
+    }
+
+    #[test]
+    #[should_panic = "more bits"]
+    fn value_too_large() {
+        let mut array = PackedArray::<5, 1>::new();
+        array.set(0, 0b10_0000);
+    }
+
+    #[test]
+    #[should_panic = "too small"]
+    fn storage_too_small() {
+        let _ = PackedSlice::<12>::new(&mut [0; 2], 11);
+    }
+}
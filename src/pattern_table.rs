@@ -0,0 +1,192 @@
+//! Module providing a decoder-style table of bit patterns.
+//!
+//! See [`PatternTable`].
+
+/// Table mapping bit patterns to payloads, e.g., for instruction decoders.
+///
+/// Each entry consists of a `(mask, value)` pair, as created by
+/// `parse_pattern` of the Function API, and a payload. A word matches an entry
+/// if all bits that are set in the mask equal the corresponding bits of the
+/// value (see `matches_pattern`).
+///
+/// - [`PatternTable::lookup`] returns the payload of the first matching entry.
+/// - [`PatternTable::lookup_most_specific`] returns the payload of the
+///   matching entry with the most fixed bits.
+/// - [`PatternTable::is_unambiguous`] checks in `const` contexts that no word
+///   matches more than one entry.
+///
+/// The table can be used with [`u8`], [`u16`], [`u32`], [`u64`], [`u128`],
+/// and [`usize`].
+///
+/// # Example
+/// ```rust
+/// use bit_ops::PatternTable;
+/// use bit_ops::bitops_u32::parse_pattern;
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Op {
+///     Add,
+///     Sub,
+///     Other,
+/// }
+///
+/// const TABLE: PatternTable<u32, Op, 3> = PatternTable::new([
+///     (parse_pattern("0000000_xxxxx_xxxxx_000_xxxxx_0110011"), Op::Add),
+///     (parse_pattern("0100000_xxxxx_xxxxx_000_xxxxx_0110011"), Op::Sub),
+///     (parse_pattern("xxxxxxx_xxxxx_xxxxx_xxx_xxxxx_xxxxxxx"), Op::Other),
+/// ]);
+///
+/// // add x3, x1, x2
+/// assert_eq!(TABLE.lookup(0x0020_81b3), Some(&Op::Add));
+/// // sub x3, x1, x2
+/// assert_eq!(TABLE.lookup(0x4020_81b3), Some(&Op::Sub));
+/// assert_eq!(TABLE.lookup(0x1234_5678), Some(&Op::Other));
+/// // The last entry is a catch-all and overlaps with all other entries.
+/// assert!(!TABLE.is_unambiguous());
+/// ```
+#[derive(Debug, Clone)]
+pub struct PatternTable<U, T, const N: usize> {
+    /// Entries in the form `((mask, value), payload)`.
+    entries: [((U, U), T); N],
+}
+
+impl<U, T, const N: usize> PatternTable<U, T, N> {
+    /// Creates a new table from entries in the form `((mask, value), payload)`.
+    #[must_use]
+    pub const fn new(entries: [((U, U), T); N]) -> Self {
+        Self { entries }
+    }
+
+    /// Returns the entries of the table.
+    #[must_use]
+    pub const fn entries(&self) -> &[((U, U), T); N] {
+        &self.entries
+    }
+}
+
+/// Implements [`PatternTable`] for the specified primitive type.
+macro_rules! impl_pattern_table {
+    ($primitive_ty:ty) => {
+        impl<T, const N: usize> PatternTable<$primitive_ty, T, N> {
+            /// Returns the payload of the first entry matching `word`, if any.
+            #[must_use]
+            pub const fn lookup(&self, word: $primitive_ty) -> Option<&T> {
+                let mut i = 0;
+                while i < N {
+                    let ((mask, value), ref payload) = self.entries[i];
+                    if word & mask == value & mask {
+                        return Some(payload);
+                    }
+                    i += 1;
+                }
+                None
+            }
+
+            /// Returns the payload of the entry matching `word` with the most
+            /// fixed bits, if any.
+            ///
+            /// If multiple matching entries have the same amount of fixed
+            /// bits, the first one wins.
+            #[must_use]
+            pub const fn lookup_most_specific(&self, word: $primitive_ty) -> Option<&T> {
+                let mut best: Option<&T> = None;
+                let mut best_fixed = 0;
+                let mut i = 0;
+                while i < N {
+                    let ((mask, value), ref payload) = self.entries[i];
+                    if word & mask == value & mask
+                        && (best.is_none() || mask.count_ones() > best_fixed)
+                    {
+                        best = Some(payload);
+                        best_fixed = mask.count_ones();
+                    }
+                    i += 1;
+                }
+                best
+            }
+
+            /// Returns the indices of the first pair of entries that both
+            /// match at least one common word, if any.
+            #[must_use]
+            pub const fn find_ambiguity(&self) -> Option<(usize, usize)> {
+                let mut i = 0;
+                while i < N {
+                    let (mask_i, value_i) = self.entries[i].0;
+                    let mut j = i + 1;
+                    while j < N {
+                        let (mask_j, value_j) = self.entries[j].0;
+                        // Two patterns overlap unless they disagree on a bit
+                        // that is fixed in both.
+                        if (value_i ^ value_j) & mask_i & mask_j == 0 {
+                            return Some((i, j));
+                        }
+                        j += 1;
+                    }
+                    i += 1;
+                }
+                None
+            }
+
+            /// Returns whether no word matches more than one entry.
+            ///
+            /// Use this in `const` contexts to reject ambiguous tables at
+            /// compile time, e.g., with
+            /// `const _: () = assert!(TABLE.is_unambiguous());`.
+            #[must_use]
+            pub const fn is_unambiguous(&self) -> bool {
+                self.find_ambiguity().is_none()
+            }
+        }
+    };
+}
+
+impl_pattern_table!(u8);
+impl_pattern_table!(u16);
+impl_pattern_table!(u32);
+impl_pattern_table!(u64);
+impl_pattern_table!(u128);
+impl_pattern_table!(usize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bitops_u8::parse_pattern;
+
+    const TABLE: PatternTable<u8, &str, 4> = PatternTable::new([
+        (parse_pattern("1xxx_xxxx"), "high"),
+        (parse_pattern("11xx_xxxx"), "higher"),
+        (parse_pattern("0xxx_xxx1"), "odd"),
+        (parse_pattern("0000_0000"), "zero"),
+    ]);
+
+    #[test]
+    fn lookup() {
+        assert_eq!(TABLE.lookup(0b1100_0000), Some(&"high"));
+        assert_eq!(TABLE.lookup(0b1000_0000), Some(&"high"));
+        assert_eq!(TABLE.lookup(0b0000_0001), Some(&"odd"));
+        assert_eq!(TABLE.lookup(0), Some(&"zero"));
+        assert_eq!(TABLE.lookup(0b0000_0010), None);
+
+        assert_eq!(TABLE.lookup_most_specific(0b1100_0000), Some(&"higher"));
+        assert_eq!(TABLE.lookup_most_specific(0b1000_0000), Some(&"high"));
+        assert_eq!(TABLE.lookup_most_specific(0), Some(&"zero"));
+        assert_eq!(TABLE.lookup_most_specific(0b0000_0010), None);
+    }
+
+    #[test]
+    fn ambiguity() {
+        const _: () = assert!(!TABLE.is_unambiguous());
+        assert_eq!(TABLE.find_ambiguity(), Some((0, 1)));
+
+        const UNAMBIGUOUS: PatternTable<u8, (), 3> = PatternTable::new([
+            (parse_pattern("1xxx_xxxx"), ()),
+            (parse_pattern("0xxx_xxx1"), ()),
+            (parse_pattern("0000_0000"), ()),
+        ]);
+        const _: () = assert!(UNAMBIGUOUS.is_unambiguous());
+
+        let empty = PatternTable::<u8, (), 0>::new([]);
+        assert!(empty.is_unambiguous());
+        assert_eq!(empty.lookup(0), None);
+    }
+}
@@ -0,0 +1,290 @@
+//! Module providing a rank/select index over bitmaps.
+//!
+//! See [`RankSelect`].
+
+use crate::bitops_u64;
+
+/// Amount of [`u64`] words covered by a single superblock.
+const SUPERBLOCK_WORDS: usize = 8;
+
+/// Every `SELECT_SAMPLE`-th set bit is sampled for [`RankSelect::select`].
+const SELECT_SAMPLE: usize = 512;
+
+/// Rank/select index over a bitmap of [`u64`] words.
+///
+/// The bitmap is treated as one gigantic integer, i.e., bit `i` is bit
+/// `i % 64` of word `i / 64`. This matches the bit positions reported by
+/// [`BitmapIter`].
+///
+/// The index stores the cumulative number of set bits for each superblock of
+/// 512 bits (8 words) and, as select directory, the superblock of every 512th
+/// set bit. This needs up to 25% (64-bit systems) of additional memory. As
+/// this crate is allocation-free, the caller provides the storage. Use
+/// [`RankSelect::storage_len`] to get the required size.
+///
+/// - [`RankSelect::rank`] is `O(1)`: one lookup plus the popcount of at most
+///   eight words.
+/// - [`RankSelect::select`] is `O(1)` if every superblock has at least one
+///   set bit: one lookup in the select directory narrows the search down to
+///   at most two superblocks, followed by a word-level
+///   [`bitops_u64::select`]. For sparser bitmaps, the superblocks between two
+///   samples are binary searched, i.e., `O(log n)` in the amount of
+///   superblocks that only hold 512 set bits together.
+///
+/// # Example
+/// ```rust
+/// use bit_ops::RankSelect;
+///
+/// let bitmap = [0b1011_u64, 0, 1 << 63, u64::MAX];
+/// let mut storage = [0; RankSelect::storage_len(4)];
+/// let index = RankSelect::new(&bitmap, &mut storage);
+///
+/// assert_eq!(index.count_ones(), 68);
+/// assert_eq!(index.rank(2), 2);
+/// assert_eq!(index.rank(192), 4);
+/// assert_eq!(index.select(2), Some(3));
+/// assert_eq!(index.select(3), Some(191));
+/// assert_eq!(index.select(68), None);
+/// ```
+///
+/// [`BitmapIter`]: crate::BitmapIter
+#[derive(Debug, Clone, Copy)]
+pub struct RankSelect<'a> {
+    words: &'a [u64],
+    /// `superblocks[i]` is the number of set bits in the words
+    /// `0..i * SUPERBLOCK_WORDS`. The last element holds the total count.
+    superblocks: &'a [usize],
+    /// `samples[k]` is the superblock containing set bit `k * SELECT_SAMPLE`.
+    samples: &'a [usize],
+}
+
+impl<'a> RankSelect<'a> {
+    /// Returns the number of superblock counts for a bitmap of `words` [`u64`]
+    /// words.
+    const fn superblocks_len(words: usize) -> usize {
+        words.div_ceil(SUPERBLOCK_WORDS) + 1
+    }
+
+    /// Returns the maximum number of select samples for a bitmap of `words`
+    /// [`u64`] words.
+    const fn samples_len(words: usize) -> usize {
+        (words * u64::BITS as usize).div_ceil(SELECT_SAMPLE)
+    }
+
+    /// Returns the number of elements of the storage required for a bitmap of
+    /// `words` [`u64`] words.
+    #[must_use]
+    pub const fn storage_len(words: usize) -> usize {
+        Self::superblocks_len(words) + Self::samples_len(words)
+    }
+
+    /// Creates a new index by computing the superblock counts and the select
+    /// samples.
+    ///
+    /// # Parameters
+    ///
+    /// - `words`: The bitmap.
+    /// - `storage`: Storage for the superblock counts and the select samples.
+    ///   Only the first [`RankSelect::storage_len`] elements are used.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `storage` is too small.
+    #[must_use]
+    pub const fn new(words: &'a [u64], storage: &'a mut [usize]) -> Self {
+        let len = Self::superblocks_len(words.len());
+        assert!(
+            storage.len() >= Self::storage_len(words.len()),
+            "storage for the index is too small"
+        );
+        let (superblocks, samples) = storage.split_at_mut(len);
+
+        let mut count = 0;
+        let mut sampled = 0;
+        let mut i = 0;
+        while i < words.len() {
+            if i % SUPERBLOCK_WORDS == 0 {
+                superblocks[i / SUPERBLOCK_WORDS] = count;
+            }
+            count += words[i].count_ones() as usize;
+            // A word has less than `SELECT_SAMPLE` set bits, so it contains
+            // at most one sample.
+            if count > sampled * SELECT_SAMPLE {
+                samples[sampled] = i / SUPERBLOCK_WORDS;
+                sampled += 1;
+            }
+            i += 1;
+        }
+        superblocks[len - 1] = count;
+
+        Self {
+            words,
+            superblocks,
+            samples: samples.split_at(sampled).0,
+        }
+    }
+
+    /// Returns the number of bits of the bitmap.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.words.len() * u64::BITS as usize
+    }
+
+    /// Returns whether the bitmap has no bits.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    /// Returns the number of set bits in the bitmap.
+    #[must_use]
+    pub const fn count_ones(&self) -> usize {
+        self.superblocks[self.superblocks.len() - 1]
+    }
+
+    /// Returns the number of set bits below the given bit position.
+    ///
+    /// # Parameters
+    ///
+    /// - `pos`: Exclusive upper bit position (`0..=self.len()`).
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `pos` is larger than [`RankSelect::len`].
+    #[must_use]
+    pub const fn rank(&self, pos: usize) -> usize {
+        assert!(pos <= self.len(), "bit position is out of range");
+        let word = pos / u64::BITS as usize;
+        let superblock = word / SUPERBLOCK_WORDS;
+        let mut count = self.superblocks[superblock];
+        let mut i = superblock * SUPERBLOCK_WORDS;
+        while i < word {
+            count += self.words[i].count_ones() as usize;
+            i += 1;
+        }
+        let bit = (pos % u64::BITS as usize) as u64;
+        if bit != 0 {
+            count += bitops_u64::rank(self.words[word], bit) as usize;
+        }
+        count
+    }
+
+    /// Returns the position of the `n`-th set bit (starting at `0`), if any.
+    ///
+    /// # Parameters
+    ///
+    /// - `n`: Zero-based index of the set bit to search.
+    #[must_use]
+    pub const fn select(&self, n: usize) -> Option<usize> {
+        if n >= self.count_ones() {
+            return None;
+        }
+
+        // The sampled set bits bound the superblock of set bit `n`. Binary
+        // search for the last superblock with a count `<= n` in between.
+        let sample = n / SELECT_SAMPLE;
+        let mut low = self.samples[sample];
+        let mut high = if sample + 1 < self.samples.len() {
+            self.samples[sample + 1] + 1
+        } else {
+            self.superblocks.len() - 1
+        };
+        while high - low > 1 {
+            let mid = low + (high - low) / 2;
+            if self.superblocks[mid] <= n {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+
+        let mut n = n - self.superblocks[low];
+        let mut i = low * SUPERBLOCK_WORDS;
+        loop {
+            let ones = self.words[i].count_ones() as usize;
+            if n < ones {
+                let bit = match bitops_u64::select(self.words[i], n as u64) {
+                    Some(bit) => bit as usize,
+                    None => unreachable!(),
+                };
+                return Some(i * u64::BITS as usize + bit);
+            }
+            n -= ones;
+            i += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BitposIteratorExt;
+    use std::vec::Vec;
+
+    #[test]
+    fn empty() {
+        let mut storage = [0; 1];
+        let index = RankSelect::new(&[], &mut storage);
+        assert!(index.is_empty());
+        assert_eq!(index.len(), 0);
+        assert_eq!(index.count_ones(), 0);
+        assert_eq!(index.rank(0), 0);
+        assert_eq!(index.select(0), None);
+    }
+
+    #[test]
+    fn rank_select() {
+        // Pseudo-random bitmap spanning several superblocks, including empty
+        // and full words.
+        let mut state = 0x1234_5678_9abc_def0_u64;
+        let words = (0..100)
+            .map(|i| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                match i % 10 {
+                    3 => 0,
+                    7 => u64::MAX,
+                    _ => state,
+                }
+            })
+            .collect::<Vec<_>>();
+        let mut storage = [0; RankSelect::storage_len(100)];
+        let index = RankSelect::new(&words, &mut storage);
+
+        let positions = words.iter().copied().bit_positions().collect::<Vec<_>>();
+        assert_eq!(index.count_ones(), positions.len());
+        assert_eq!(index.select(positions.len()), None);
+        for (n, &pos) in positions.iter().enumerate() {
+            assert_eq!(index.select(n), Some(pos));
+            assert_eq!(index.rank(pos), n);
+            assert_eq!(index.rank(pos + 1), n + 1);
+        }
+        assert_eq!(index.rank(index.len()), positions.len());
+    }
+
+    #[test]
+    fn select_sparse() {
+        // Long runs of empty superblocks between the set bits.
+        let mut words = [0_u64; 200];
+        words[0] = 1;
+        words[70] = u64::MAX;
+        words[199] = 1 << 63;
+        words[100..110].fill(u64::MAX);
+        let mut storage = [0; RankSelect::storage_len(200)];
+        let index = RankSelect::new(&words, &mut storage);
+
+        let positions = words.iter().copied().bit_positions().collect::<Vec<_>>();
+        for (n, &pos) in positions.iter().enumerate() {
+            assert_eq!(index.select(n), Some(pos));
+        }
+        assert_eq!(index.select(positions.len()), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn storage_too_small() {
+        let mut storage = [0; RankSelect::storage_len(9) - 1];
+        let _ = RankSelect::new(&[0; 9], &mut storage);
+    }
+}
@@ -21,184 +21,912 @@ LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
 OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 SOFTWARE.
 */
+/// Declares a trait or a trait implementation as `const` if the
+/// `nightly-const-trait` feature is enabled.
+#[cfg(feature = "nightly-const-trait")]
+macro_rules! const_trait {
+    (impl $($rest:tt)*) => {
+        impl const $($rest)*
+    };
+    ($(#[$meta:meta])* $vis:vis trait $($rest:tt)*) => {
+        $(#[$meta])* $vis const trait $($rest)*
+    };
+}
+
+/// Declares a trait or a trait implementation as `const` if the
+/// `nightly-const-trait` feature is enabled.
+#[cfg(not(feature = "nightly-const-trait"))]
+macro_rules! const_trait {
+    (impl $($rest:tt)*) => {
+        impl $($rest)*
+    };
+    ($(#[$meta:meta])* $vis:vis trait $($rest:tt)*) => {
+        $(#[$meta])* $vis trait $($rest)*
+    };
+}
+
 /// Implements the trait for the primitive type by forwarding all calls to
 /// the function API.
 macro_rules! impl_trait {
     ($primitive_ty:ty) => {
-        impl BitOps for $primitive_ty {
-            #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::set_bit`],")]
-            #[doc = concat!("but as associated function (method) on `", stringify!($primitive_ty), "`.")]
+        const_trait! {
+            impl BitOps for $primitive_ty {
+                #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::set_bit`],")]
+                #[doc = concat!("but as associated function (method) on `", stringify!($primitive_ty), "`.")]
+                #[doc = ""] // newline needed so that markdown links work
+                #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::set_bit`]: crate::bitops_", stringify!($primitive_ty), "::set_bit")]
+                #[inline]
+                fn set_bit(self, bit: Self) -> Self {
+                    paste::paste! {
+                        $crate::[< bitops _ $primitive_ty >]::set_bit(self, bit)
+                    }
+                }
+
+                #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::set_bit_exact`],")]
+                #[doc = concat!("but as associated function (method) on `", stringify!($primitive_ty), "`.")]
+                #[doc = ""] // newline needed so that markdown links work
+                #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::set_bit_exact`]: crate::bitops_", stringify!($primitive_ty), "::set_bit_exact")]
+                #[inline]
+                fn set_bit_exact(self, bit: Self, value: bool) -> Self {
+                    paste::paste! {
+                        $crate::[< bitops _ $primitive_ty >]::set_bit_exact(self, bit, value)
+                    }
+                }
+
+                #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::clear_bit`],")]
+                #[doc = concat!("but as associated function (method) on `", stringify!($primitive_ty), "`.")]
+                #[doc = ""] // newline needed so that markdown links work
+                #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::clear_bit`]: crate::bitops_", stringify!($primitive_ty), "::clear_bit")]
+                #[inline]
+                fn clear_bit(self, bit: Self) -> Self {
+                    paste::paste! {
+                        $crate::[< bitops _ $primitive_ty >]::clear_bit(self, bit)
+                    }
+                }
+
+                #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::is_set`],")]
+                #[doc = concat!("but as associated function (method) on `", stringify!($primitive_ty), "`.")]
+                #[doc = ""] // newline needed so that markdown links work
+                #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::is_set`]: crate::bitops_", stringify!($primitive_ty), "::is_set")]
+                #[inline]
+                fn is_set(self, bit: Self) -> bool {
+                    paste::paste! {
+                        $crate::[< bitops _ $primitive_ty >]::is_set(self, bit)
+                    }
+                }
+
+                #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::get_bit`],")]
+                #[doc = concat!("but as associated function (method) on `", stringify!($primitive_ty), "`.")]
+                #[doc = ""] // newline needed so that markdown links work
+                #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::get_bit`]: crate::bitops_", stringify!($primitive_ty), "::get_bit")]
+                #[inline]
+                fn get_bit(self, bit: Self) -> Self {
+                    paste::paste! {
+                        $crate::[< bitops _ $primitive_ty >]::get_bit(self, bit)
+                    }
+                }
+
+                #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::toggle_bit`],")]
+                #[doc = concat!("but as associated function (method) on `", stringify!($primitive_ty), "`.")]
+                #[doc = ""] // newline needed so that markdown links work
+                #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::toggle_bit`]: crate::bitops_", stringify!($primitive_ty), "::toggle_bit")]
+                #[inline]
+                fn toggle_bit(self, bit: Self) -> Self {
+                    paste::paste! {
+                        $crate::[< bitops _ $primitive_ty >]::toggle_bit(self, bit)
+                    }
+                }
+
+                #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::toggle_bits`],")]
+                #[doc = concat!("but as associated function (method) on `", stringify!($primitive_ty), "`.")]
+                #[doc = ""] // newline needed so that markdown links work
+                #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::toggle_bits`]: crate::bitops_", stringify!($primitive_ty), "::toggle_bits")]
+                #[inline]
+                fn toggle_bits(self, bits: Self, shift: Self) -> Self {
+                    paste::paste! {
+                        $crate::[< bitops _ $primitive_ty >]::toggle_bits(self, bits, shift)
+                    }
+                }
+
+                #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::set_bits`],")]
+                #[doc = concat!("but as associated function (method) on `", stringify!($primitive_ty), "`.")]
+                #[doc = ""] // newline needed so that markdown links work
+                #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::set_bits`]: crate::bitops_", stringify!($primitive_ty), "::set_bits")]
+                #[inline]
+                fn set_bits(self, value: Self, value_bits: Self, value_shift: Self) -> Self {
+                    paste::paste! {
+                        $crate::[< bitops _ $primitive_ty >]::set_bits(self, value, value_bits, value_shift)
+                    }
+                }
+
+                #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::set_bits_n`],")]
+                #[doc = concat!("but as associated function (method) on `", stringify!($primitive_ty), "`.")]
+                #[doc = ""] // newline needed so that markdown links work
+                #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::set_bits_n`]: crate::bitops_", stringify!($primitive_ty), "::set_bits_n")]
+                #[inline]
+                fn set_bits_n(self, ops: &[(Self, Self, Self)]) -> Self {
+                    paste::paste! {
+                        $crate::[< bitops _ $primitive_ty >]::set_bits_n(self, ops)
+                    }
+                }
+
+                #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::set_bits_exact`],")]
+                #[doc = concat!("but as associated function (method) on `", stringify!($primitive_ty), "`.")]
+                #[doc = ""] // newline needed so that markdown links work
+                #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::set_bits_exact`]: crate::bitops_", stringify!($primitive_ty), "::set_bits_exact")]
+                #[inline]
+                fn set_bits_exact(self, value: Self, value_bits: Self, value_shift: Self) -> Self {
+                    paste::paste! {
+                        $crate::[< bitops _ $primitive_ty >]::set_bits_exact(self, value, value_bits, value_shift)
+                    }
+                }
+
+                #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::set_bits_exact_n`],")]
+                #[doc = concat!("but as associated function (method) on `", stringify!($primitive_ty), "`.")]
+                #[doc = ""] // newline needed so that markdown links work
+                #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::set_bits_exact_n`]: crate::bitops_", stringify!($primitive_ty), "::set_bits_exact_n")]
+                #[inline]
+                fn set_bits_exact_n(self, ops: &[(Self, Self, Self)]) -> Self {
+                    paste::paste! {
+                        $crate::[< bitops _ $primitive_ty >]::set_bits_exact_n(self, ops)
+                    }
+                }
+
+                #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::clear_bits`],")]
+                #[doc = concat!("but as associated function (method) on `", stringify!($primitive_ty), "`.")]
+                #[doc = ""] // newline needed so that markdown links work
+                #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::clear_bits`]: crate::bitops_", stringify!($primitive_ty), "::clear_bits")]
+                #[inline]
+                fn clear_bits(self, clear_mask: Self) -> Self {
+                    paste::paste! {
+                        $crate::[< bitops _ $primitive_ty >]::clear_bits(self, clear_mask)
+                    }
+                }
+
+                #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::highest_bit`],")]
+                #[doc = concat!("but as associated function (method) on `", stringify!($primitive_ty), "`.")]
+                #[doc = ""] // newline needed so that markdown links work
+                #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::highest_bit`]: crate::bitops_", stringify!($primitive_ty), "::highest_bit")]
+                #[inline]
+                fn highest_bit(self) -> Option<Self> {
+                    paste::paste! {
+                        $crate::[< bitops _ $primitive_ty >]::highest_bit(self)
+                    }
+                }
+
+                #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::lowest_bit`],")]
+                #[doc = concat!("but as associated function (method) on `", stringify!($primitive_ty), "`.")]
+                #[doc = ""] // newline needed so that markdown links work
+                #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::lowest_bit`]: crate::bitops_", stringify!($primitive_ty), "::lowest_bit")]
+                #[inline]
+                fn lowest_bit(self) -> Option<Self> {
+                    paste::paste! {
+                        $crate::[< bitops _ $primitive_ty >]::lowest_bit(self)
+                    }
+                }
+
+                #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::get_bits`],")]
+                #[doc = concat!("but as associated function (method) on `", stringify!($primitive_ty), "`.")]
+                #[doc = ""] // newline needed so that markdown links work
+                #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::get_bits`]: crate::bitops_", stringify!($primitive_ty), "::get_bits")]
+                #[inline]
+                fn get_bits(self, value_bits: Self, value_shift: Self) -> Self {
+                    paste::paste! {
+                        $crate::[< bitops _ $primitive_ty >]::get_bits(self, value_bits, value_shift)
+                    }
+                }
+
+                #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::create_mask`],")]
+                #[doc = concat!("but as associated function (method) on `", stringify!($primitive_ty), "`.")]
+                #[doc = ""] // newline needed so that markdown links work
+                #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::create_mask`]: crate::bitops_", stringify!($primitive_ty), "::create_mask")]
+                #[inline]
+                fn create_mask(bits: Self) -> Self {
+                    paste::paste! {
+                        $crate::[< bitops _ $primitive_ty >]::create_mask(bits)
+                    }
+                }
+
+                #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::reverse_low_bits`],")]
+                #[doc = concat!("but as associated function (method) on `", stringify!($primitive_ty), "`.")]
+                #[doc = ""] // newline needed so that markdown links work
+                #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::reverse_low_bits`]: crate::bitops_", stringify!($primitive_ty), "::reverse_low_bits")]
+                #[inline]
+                fn reverse_low_bits(self, n: Self) -> Self {
+                    paste::paste! {
+                        $crate::[< bitops _ $primitive_ty >]::reverse_low_bits(self, n)
+                    }
+                }
+
+                #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::reverse_bits_in_field`],")]
+                #[doc = concat!("but as associated function (method) on `", stringify!($primitive_ty), "`.")]
+                #[doc = ""] // newline needed so that markdown links work
+                #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::reverse_bits_in_field`]: crate::bitops_", stringify!($primitive_ty), "::reverse_bits_in_field")]
+                #[inline]
+                fn reverse_bits_in_field(self, bits: Self, shift: Self) -> Self {
+                    paste::paste! {
+                        $crate::[< bitops _ $primitive_ty >]::reverse_bits_in_field(self, bits, shift)
+                    }
+                }
+
+                #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::bit_reverse_permutation`],")]
+                #[doc = concat!("but as associated function (method) on `", stringify!($primitive_ty), "`.")]
+                #[doc = ""] // newline needed so that markdown links work
+                #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::bit_reverse_permutation`]: crate::bitops_", stringify!($primitive_ty), "::bit_reverse_permutation")]
+                #[inline]
+                fn bit_reverse_permutation(order: Self) -> $crate::BitReversePermutationIter<Self> {
+                    paste::paste! {
+                        $crate::[< bitops _ $primitive_ty >]::bit_reverse_permutation(order)
+                    }
+                }
+
+                #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::to_gray`],")]
+                #[doc = concat!("but as associated function (method) on `", stringify!($primitive_ty), "`.")]
+                #[doc = ""] // newline needed so that markdown links work
+                #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::to_gray`]: crate::bitops_", stringify!($primitive_ty), "::to_gray")]
+                #[inline]
+                fn to_gray(self) -> Self {
+                    paste::paste! {
+                        $crate::[< bitops _ $primitive_ty >]::to_gray(self)
+                    }
+                }
+
+                #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::from_gray`],")]
+                #[doc = concat!("but as associated function (method) on `", stringify!($primitive_ty), "`.")]
+                #[doc = ""] // newline needed so that markdown links work
+                #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::from_gray`]: crate::bitops_", stringify!($primitive_ty), "::from_gray")]
+                #[inline]
+                fn from_gray(self) -> Self {
+                    paste::paste! {
+                        $crate::[< bitops _ $primitive_ty >]::from_gray(self)
+                    }
+                }
+
+                #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::parity`],")]
+                #[doc = concat!("but as associated function (method) on `", stringify!($primitive_ty), "`.")]
+                #[doc = ""] // newline needed so that markdown links work
+                #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::parity`]: crate::bitops_", stringify!($primitive_ty), "::parity")]
+                #[inline]
+                fn parity(self) -> bool {
+                    paste::paste! {
+                        $crate::[< bitops _ $primitive_ty >]::parity(self)
+                    }
+                }
+
+                #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::parity_of_field`],")]
+                #[doc = concat!("but as associated function (method) on `", stringify!($primitive_ty), "`.")]
+                #[doc = ""] // newline needed so that markdown links work
+                #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::parity_of_field`]: crate::bitops_", stringify!($primitive_ty), "::parity_of_field")]
+                #[inline]
+                fn parity_of_field(self, bits: Self, shift: Self) -> bool {
+                    paste::paste! {
+                        $crate::[< bitops _ $primitive_ty >]::parity_of_field(self, bits, shift)
+                    }
+                }
+
+                #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::count_ones_in_field`],")]
+                #[doc = concat!("but as associated function (method) on `", stringify!($primitive_ty), "`.")]
+                #[doc = ""] // newline needed so that markdown links work
+                #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::count_ones_in_field`]: crate::bitops_", stringify!($primitive_ty), "::count_ones_in_field")]
+                #[inline]
+                fn count_ones_in_field(self, bits: Self, shift: Self) -> Self {
+                    paste::paste! {
+                        $crate::[< bitops _ $primitive_ty >]::count_ones_in_field(self, bits, shift)
+                    }
+                }
+
+                #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::rank`],")]
+                #[doc = concat!("but as associated function (method) on `", stringify!($primitive_ty), "`.")]
+                #[doc = ""] // newline needed so that markdown links work
+                #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::rank`]: crate::bitops_", stringify!($primitive_ty), "::rank")]
+                #[inline]
+                fn rank(self, pos: Self) -> Self {
+                    paste::paste! {
+                        $crate::[< bitops _ $primitive_ty >]::rank(self, pos)
+                    }
+                }
+
+                #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::select`],")]
+                #[doc = concat!("but as associated function (method) on `", stringify!($primitive_ty), "`.")]
+                #[doc = ""] // newline needed so that markdown links work
+                #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::select`]: crate::bitops_", stringify!($primitive_ty), "::select")]
+                #[inline]
+                fn select(self, n: Self) -> Option<Self> {
+                    paste::paste! {
+                        $crate::[< bitops _ $primitive_ty >]::select(self, n)
+                    }
+                }
+
+                #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::align_down`],")]
+                #[doc = concat!("but as associated function (method) on `", stringify!($primitive_ty), "`.")]
+                #[doc = ""] // newline needed so that markdown links work
+                #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::align_down`]: crate::bitops_", stringify!($primitive_ty), "::align_down")]
+                #[inline]
+                fn align_down(self, align_bits: Self) -> Self {
+                    paste::paste! {
+                        $crate::[< bitops _ $primitive_ty >]::align_down(self, align_bits)
+                    }
+                }
+
+                #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::align_up`],")]
+                #[doc = concat!("but as associated function (method) on `", stringify!($primitive_ty), "`.")]
+                #[doc = ""] // newline needed so that markdown links work
+                #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::align_up`]: crate::bitops_", stringify!($primitive_ty), "::align_up")]
+                #[inline]
+                fn align_up(self, align_bits: Self) -> Self {
+                    paste::paste! {
+                        $crate::[< bitops _ $primitive_ty >]::align_up(self, align_bits)
+                    }
+                }
+
+                #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::checked_align_up`],")]
+                #[doc = concat!("but as associated function (method) on `", stringify!($primitive_ty), "`.")]
+                #[doc = ""] // newline needed so that markdown links work
+                #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::checked_align_up`]: crate::bitops_", stringify!($primitive_ty), "::checked_align_up")]
+                #[inline]
+                fn checked_align_up(self, align_bits: Self) -> Option<Self> {
+                    paste::paste! {
+                        $crate::[< bitops _ $primitive_ty >]::checked_align_up(self, align_bits)
+                    }
+                }
+
+                #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::is_aligned`],")]
+                #[doc = concat!("but as associated function (method) on `", stringify!($primitive_ty), "`.")]
+                #[doc = ""] // newline needed so that markdown links work
+                #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::is_aligned`]: crate::bitops_", stringify!($primitive_ty), "::is_aligned")]
+                #[inline]
+                fn is_aligned(self, align_bits: Self) -> bool {
+                    paste::paste! {
+                        $crate::[< bitops _ $primitive_ty >]::is_aligned(self, align_bits)
+                    }
+                }
+
+                #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::is_power_of_two_mask`],")]
+                #[doc = concat!("but as associated function (method) on `", stringify!($primitive_ty), "`.")]
+                #[doc = ""] // newline needed so that markdown links work
+                #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::is_power_of_two_mask`]: crate::bitops_", stringify!($primitive_ty), "::is_power_of_two_mask")]
+                #[inline]
+                fn is_power_of_two_mask(self) -> bool {
+                    paste::paste! {
+                        $crate::[< bitops _ $primitive_ty >]::is_power_of_two_mask(self)
+                    }
+                }
+
+                #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::largest_aligned_block`],")]
+                #[doc = concat!("but as associated function (method) on `", stringify!($primitive_ty), "`.")]
+                #[doc = ""] // newline needed so that markdown links work
+                #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::largest_aligned_block`]: crate::bitops_", stringify!($primitive_ty), "::largest_aligned_block")]
+                #[inline]
+                fn largest_aligned_block(self, size: Self) -> Option<Self> {
+                    paste::paste! {
+                        $crate::[< bitops _ $primitive_ty >]::largest_aligned_block(self, size)
+                    }
+                }
+
+                #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::round_to_pow2`],")]
+                #[doc = concat!("but as associated function (method) on `", stringify!($primitive_ty), "`.")]
+                #[doc = ""] // newline needed so that markdown links work
+                #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::round_to_pow2`]: crate::bitops_", stringify!($primitive_ty), "::round_to_pow2")]
+                #[inline]
+                fn round_to_pow2(self) -> Option<Self> {
+                    paste::paste! {
+                        $crate::[< bitops _ $primitive_ty >]::round_to_pow2(self)
+                    }
+                }
+
+                #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::rotate_field_left`],")]
+                #[doc = concat!("but as associated function (method) on `", stringify!($primitive_ty), "`.")]
+                #[doc = ""] // newline needed so that markdown links work
+                #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::rotate_field_left`]: crate::bitops_", stringify!($primitive_ty), "::rotate_field_left")]
+                #[inline]
+                fn rotate_field_left(self, bits: Self, shift: Self, n: Self) -> Self {
+                    paste::paste! {
+                        $crate::[< bitops _ $primitive_ty >]::rotate_field_left(self, bits, shift, n)
+                    }
+                }
+
+                #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::rotate_field_right`],")]
+                #[doc = concat!("but as associated function (method) on `", stringify!($primitive_ty), "`.")]
+                #[doc = ""] // newline needed so that markdown links work
+                #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::rotate_field_right`]: crate::bitops_", stringify!($primitive_ty), "::rotate_field_right")]
+                #[inline]
+                fn rotate_field_right(self, bits: Self, shift: Self, n: Self) -> Self {
+                    paste::paste! {
+                        $crate::[< bitops _ $primitive_ty >]::rotate_field_right(self, bits, shift, n)
+                    }
+                }
+
+                #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::isolate_lowest_set`],")]
+                #[doc = concat!("but as associated function (method) on `", stringify!($primitive_ty), "`.")]
+                #[doc = ""] // newline needed so that markdown links work
+                #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::isolate_lowest_set`]: crate::bitops_", stringify!($primitive_ty), "::isolate_lowest_set")]
+                #[inline]
+                fn isolate_lowest_set(self) -> Self {
+                    paste::paste! {
+                        $crate::[< bitops _ $primitive_ty >]::isolate_lowest_set(self)
+                    }
+                }
+
+                #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::clear_lowest_set`],")]
+                #[doc = concat!("but as associated function (method) on `", stringify!($primitive_ty), "`.")]
+                #[doc = ""] // newline needed so that markdown links work
+                #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::clear_lowest_set`]: crate::bitops_", stringify!($primitive_ty), "::clear_lowest_set")]
+                #[inline]
+                fn clear_lowest_set(self) -> Self {
+                    paste::paste! {
+                        $crate::[< bitops _ $primitive_ty >]::clear_lowest_set(self)
+                    }
+                }
+
+                #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::isolate_lowest_zero`],")]
+                #[doc = concat!("but as associated function (method) on `", stringify!($primitive_ty), "`.")]
+                #[doc = ""] // newline needed so that markdown links work
+                #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::isolate_lowest_zero`]: crate::bitops_", stringify!($primitive_ty), "::isolate_lowest_zero")]
+                #[inline]
+                fn isolate_lowest_zero(self) -> Self {
+                    paste::paste! {
+                        $crate::[< bitops _ $primitive_ty >]::isolate_lowest_zero(self)
+                    }
+                }
+
+                #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::smear_right`],")]
+                #[doc = concat!("but as associated function (method) on `", stringify!($primitive_ty), "`.")]
+                #[doc = ""] // newline needed so that markdown links work
+                #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::smear_right`]: crate::bitops_", stringify!($primitive_ty), "::smear_right")]
+                #[inline]
+                fn smear_right(self) -> Self {
+                    paste::paste! {
+                        $crate::[< bitops _ $primitive_ty >]::smear_right(self)
+                    }
+                }
+
+                #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::mask_up_to_lowest_set`],")]
+                #[doc = concat!("but as associated function (method) on `", stringify!($primitive_ty), "`.")]
+                #[doc = ""] // newline needed so that markdown links work
+                #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::mask_up_to_lowest_set`]: crate::bitops_", stringify!($primitive_ty), "::mask_up_to_lowest_set")]
+                #[inline]
+                fn mask_up_to_lowest_set(self) -> Self {
+                    paste::paste! {
+                        $crate::[< bitops _ $primitive_ty >]::mask_up_to_lowest_set(self)
+                    }
+                }
+
+                #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::next_same_popcount`],")]
+                #[doc = concat!("but as associated function (method) on `", stringify!($primitive_ty), "`.")]
+                #[doc = ""] // newline needed so that markdown links work
+                #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::next_same_popcount`]: crate::bitops_", stringify!($primitive_ty), "::next_same_popcount")]
+                #[inline]
+                fn next_same_popcount(self) -> Option<Self> {
+                    paste::paste! {
+                        $crate::[< bitops _ $primitive_ty >]::next_same_popcount(self)
+                    }
+                }
+
+                #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::next_set_bit`],")]
+                #[doc = concat!("but as associated function (method) on `", stringify!($primitive_ty), "`.")]
+                #[doc = ""] // newline needed so that markdown links work
+                #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::next_set_bit`]: crate::bitops_", stringify!($primitive_ty), "::next_set_bit")]
+                #[inline]
+                fn next_set_bit(self, from: Self) -> Option<Self> {
+                    paste::paste! {
+                        $crate::[< bitops _ $primitive_ty >]::next_set_bit(self, from)
+                    }
+                }
+
+                #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::prev_set_bit`],")]
+                #[doc = concat!("but as associated function (method) on `", stringify!($primitive_ty), "`.")]
+                #[doc = ""] // newline needed so that markdown links work
+                #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::prev_set_bit`]: crate::bitops_", stringify!($primitive_ty), "::prev_set_bit")]
+                #[inline]
+                fn prev_set_bit(self, from: Self) -> Option<Self> {
+                    paste::paste! {
+                        $crate::[< bitops _ $primitive_ty >]::prev_set_bit(self, from)
+                    }
+                }
+
+                #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::next_clear_bit`],")]
+                #[doc = concat!("but as associated function (method) on `", stringify!($primitive_ty), "`.")]
+                #[doc = ""] // newline needed so that markdown links work
+                #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::next_clear_bit`]: crate::bitops_", stringify!($primitive_ty), "::next_clear_bit")]
+                #[inline]
+                fn next_clear_bit(self, from: Self) -> Option<Self> {
+                    paste::paste! {
+                        $crate::[< bitops _ $primitive_ty >]::next_clear_bit(self, from)
+                    }
+                }
+
+                #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::prev_clear_bit`],")]
+                #[doc = concat!("but as associated function (method) on `", stringify!($primitive_ty), "`.")]
+                #[doc = ""] // newline needed so that markdown links work
+                #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::prev_clear_bit`]: crate::bitops_", stringify!($primitive_ty), "::prev_clear_bit")]
+                #[inline]
+                fn prev_clear_bit(self, from: Self) -> Option<Self> {
+                    paste::paste! {
+                        $crate::[< bitops _ $primitive_ty >]::prev_clear_bit(self, from)
+                    }
+                }
+
+                #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::matches_pattern`],")]
+                #[doc = concat!("but as associated function (method) on `", stringify!($primitive_ty), "`.")]
+                #[doc = ""] // newline needed so that markdown links work
+                #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::matches_pattern`]: crate::bitops_", stringify!($primitive_ty), "::matches_pattern")]
+                #[inline]
+                fn matches_pattern(self, mask: Self, value: Self) -> bool {
+                    paste::paste! {
+                        $crate::[< bitops _ $primitive_ty >]::matches_pattern(self, mask, value)
+                    }
+                }
+            }
+        }
+    };
+}
+
+/// Implements [`BitOps`] for a wrapper of the primitive type, such as
+/// [`Wrapping`], by forwarding all calls to the implementation for the
+/// primitive type.
+///
+/// Most methods only differ in their arguments and in how the result is
+/// wrapped, so they are listed by the shape of their result. The remaining
+/// methods are spelled out.
+///
+/// [`BitOps`]: crate::BitOps
+/// [`Wrapping`]: core::num::Wrapping
+macro_rules! impl_trait_wrapper {
+    ($wrapper:ident, $primitive_ty:ty) => {
+        const_trait! {
+            impl BitOps for $wrapper<$primitive_ty> {
+                impl_trait_wrapper! {
+                    @forward $wrapper, $primitive_ty;
+                    wrapped: set_bit(bit), clear_bit(bit), get_bit(bit),
+                        toggle_bit(bit), toggle_bits(bits, shift),
+                        set_bits(value, value_bits, value_shift),
+                        set_bits_exact(value, value_bits, value_shift),
+                        clear_bits(clear_mask), get_bits(value_bits, value_shift),
+                        reverse_low_bits(n), reverse_bits_in_field(bits, shift),
+                        to_gray(), from_gray(), count_ones_in_field(bits, shift),
+                        rank(pos), align_down(align_bits), align_up(align_bits),
+                        rotate_field_left(bits, shift, n),
+                        rotate_field_right(bits, shift, n), isolate_lowest_set(),
+                        clear_lowest_set(), isolate_lowest_zero(), smear_right(),
+                        mask_up_to_lowest_set();
+                    optional: highest_bit(), lowest_bit(), select(n),
+                        checked_align_up(align_bits), largest_aligned_block(size),
+                        round_to_pow2(), next_same_popcount(), next_set_bit(from),
+                        prev_set_bit(from), next_clear_bit(from),
+                        prev_clear_bit(from);
+                    unwrapped: is_set(bit), parity(), parity_of_field(bits, shift),
+                        is_aligned(align_bits), is_power_of_two_mask(),
+                        matches_pattern(mask, value);
+                }
+
+                impl_trait_wrapper! {
+                    @doc $wrapper, $primitive_ty;
+                    fn set_bit_exact(self, bit: Self, value: bool) -> Self {
+                        $wrapper(<$primitive_ty as BitOps>::set_bit_exact(self.0, bit.0, value))
+                    }
+                }
+
+                impl_trait_wrapper! {
+                    @doc $wrapper, $primitive_ty;
+                    fn set_bits_n(self, ops: &[(Self, Self, Self)]) -> Self {
+                        let mut base = self;
+                        let mut i = 0;
+                        while i < ops.len() {
+                            let (value, value_bits, value_shift) = ops[i];
+                            base = base.set_bits(value, value_bits, value_shift);
+                            i += 1;
+                        }
+                        base
+                    }
+                }
+
+                impl_trait_wrapper! {
+                    @doc $wrapper, $primitive_ty;
+                    fn set_bits_exact_n(self, ops: &[(Self, Self, Self)]) -> Self {
+                        let mut base = self;
+                        let mut i = 0;
+                        while i < ops.len() {
+                            let (value, value_bits, value_shift) = ops[i];
+                            base = base.set_bits_exact(value, value_bits, value_shift);
+                            i += 1;
+                        }
+                        base
+                    }
+                }
+
+                impl_trait_wrapper! {
+                    @doc $wrapper, $primitive_ty;
+                    fn create_mask(bits: Self) -> Self {
+                        $wrapper(<$primitive_ty as BitOps>::create_mask(bits.0))
+                    }
+                }
+
+                impl_trait_wrapper! {
+                    @doc $wrapper, $primitive_ty;
+                    fn bit_reverse_permutation(order: Self) -> BitReversePermutationIter<Self> {
+                        BitReversePermutationIter::<Self>::new(order)
+                    }
+                }
+            }
+        }
+    };
+    (
+        @forward $wrapper:ident, $primitive_ty:ty;
+        wrapped: $($wrapped:ident($($wrapped_arg:ident),*)),*;
+        optional: $($optional:ident($($optional_arg:ident),*)),*;
+        unwrapped: $($unwrapped:ident($($unwrapped_arg:ident),*)),*;
+    ) => {
+        $(
+            impl_trait_wrapper! {
+                @doc $wrapper, $primitive_ty;
+                fn $wrapped(self, $($wrapped_arg: Self),*) -> Self {
+                    $wrapper(<$primitive_ty as BitOps>::$wrapped(self.0, $($wrapped_arg.0),*))
+                }
+            }
+        )*
+        $(
+            impl_trait_wrapper! {
+                @doc $wrapper, $primitive_ty;
+                fn $optional(self, $($optional_arg: Self),*) -> Option<Self> {
+                    match <$primitive_ty as BitOps>::$optional(self.0, $($optional_arg.0),*) {
+                        Some(value) => Some($wrapper(value)),
+                        None => None,
+                    }
+                }
+            }
+        )*
+        $(
+            impl_trait_wrapper! {
+                @doc $wrapper, $primitive_ty;
+                fn $unwrapped(self, $($unwrapped_arg: Self),*) -> bool {
+                    <$primitive_ty as BitOps>::$unwrapped(self.0, $($unwrapped_arg.0),*)
+                }
+            }
+        )*
+    };
+    (@doc $wrapper:ident, $primitive_ty:ty; fn $name:ident $($rest:tt)*) => {
+        #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::", stringify!($name), "`],")]
+        #[doc = concat!("but as associated function (method) on `", stringify!($wrapper), "<", stringify!($primitive_ty), ">`.")]
+        #[doc = ""] // newline needed so that markdown links work
+        #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::", stringify!($name), "`]: crate::bitops_", stringify!($primitive_ty), "::", stringify!($name))]
+        #[inline]
+        fn $name $($rest)*
+    };
+}
+
+/// Implements [`BitOpsNonZero`] for the non-zero type by forwarding all calls
+/// to the function API.
+///
+/// [`BitOpsNonZero`]: crate::BitOpsNonZero
+macro_rules! impl_trait_nonzero {
+    ($nonzero_ty:ty, $primitive_ty:ty) => {
+        impl BitOpsNonZero for $nonzero_ty {
+            type Primitive = $primitive_ty;
+
+            #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::highest_bit`],")]
+            #[doc = concat!("but as associated function (method) on `", stringify!($nonzero_ty), "`.")]
             #[doc = ""] // newline needed so that markdown links work
-            #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::set_bit`]: crate::bitops_", stringify!($primitive_ty), "::set_bit")]
+            #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::highest_bit`]: crate::bitops_", stringify!($primitive_ty), "::highest_bit")]
+            #[inline]
+            fn highest_bit(self) -> $primitive_ty {
+                self.ilog2() as $primitive_ty
+            }
+
+            #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::lowest_bit`],")]
+            #[doc = concat!("but as associated function (method) on `", stringify!($nonzero_ty), "`.")]
+            #[doc = ""] // newline needed so that markdown links work
+            #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::lowest_bit`]: crate::bitops_", stringify!($primitive_ty), "::lowest_bit")]
+            #[inline]
+            fn lowest_bit(self) -> $primitive_ty {
+                self.trailing_zeros() as $primitive_ty
+            }
+
+            #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::is_set`],")]
+            #[doc = concat!("but as associated function (method) on `", stringify!($nonzero_ty), "`.")]
+            #[doc = ""] // newline needed so that markdown links work
+            #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::is_set`]: crate::bitops_", stringify!($primitive_ty), "::is_set")]
             #[inline]
-            fn set_bit(self, bit: Self) -> Self {
+            fn is_set(self, bit: $primitive_ty) -> bool {
                 paste::paste! {
-                    $crate::[< bitops _ $primitive_ty >]::set_bit(self, bit)
+                    $crate::[< bitops _ $primitive_ty >]::is_set(self.get(), bit)
                 }
             }
 
-            #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::set_bit_exact`],")]
-            #[doc = concat!("but as associated function (method) on `", stringify!($primitive_ty), "`.")]
+            #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::get_bit`],")]
+            #[doc = concat!("but as associated function (method) on `", stringify!($nonzero_ty), "`.")]
             #[doc = ""] // newline needed so that markdown links work
-            #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::set_bit_exact`]: crate::bitops_", stringify!($primitive_ty), "::set_bit_exact")]
+            #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::get_bit`]: crate::bitops_", stringify!($primitive_ty), "::get_bit")]
             #[inline]
-            fn set_bit_exact(self, bit: Self, value: bool) -> Self {
+            fn get_bit(self, bit: $primitive_ty) -> $primitive_ty {
                 paste::paste! {
-                    $crate::[< bitops _ $primitive_ty >]::set_bit_exact(self, bit, value)
+                    $crate::[< bitops _ $primitive_ty >]::get_bit(self.get(), bit)
                 }
             }
 
-            #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::clear_bit`],")]
-            #[doc = concat!("but as associated function (method) on `", stringify!($primitive_ty), "`.")]
+            #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::get_bits`],")]
+            #[doc = concat!("but as associated function (method) on `", stringify!($nonzero_ty), "`.")]
             #[doc = ""] // newline needed so that markdown links work
-            #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::clear_bit`]: crate::bitops_", stringify!($primitive_ty), "::clear_bit")]
+            #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::get_bits`]: crate::bitops_", stringify!($primitive_ty), "::get_bits")]
             #[inline]
-            fn clear_bit(self, bit: Self) -> Self {
+            fn get_bits(self, value_bits: $primitive_ty, value_shift: $primitive_ty) -> $primitive_ty {
                 paste::paste! {
-                    $crate::[< bitops _ $primitive_ty >]::clear_bit(self, bit)
+                    $crate::[< bitops _ $primitive_ty >]::get_bits(self.get(), value_bits, value_shift)
                 }
             }
 
-            #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::is_set`],")]
+            #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::parity`],")]
+            #[doc = concat!("but as associated function (method) on `", stringify!($nonzero_ty), "`.")]
+            #[doc = ""] // newline needed so that markdown links work
+            #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::parity`]: crate::bitops_", stringify!($primitive_ty), "::parity")]
+            #[inline]
+            fn parity(self) -> bool {
+                paste::paste! {
+                    $crate::[< bitops _ $primitive_ty >]::parity(self.get())
+                }
+            }
+
+            #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::isolate_lowest_set`],")]
+            #[doc = concat!("but as associated function (method) on `", stringify!($nonzero_ty), "`.")]
+            #[doc = ""] // newline needed so that markdown links work
+            #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::isolate_lowest_set`]: crate::bitops_", stringify!($primitive_ty), "::isolate_lowest_set")]
+            #[inline]
+            fn isolate_lowest_set(self) -> Self {
+                // `2^trailing_zeros` always fits, so this never saturates.
+                Self::MIN
+                    .saturating_add(1)
+                    .saturating_pow(self.trailing_zeros())
+            }
+        }
+    };
+}
+
+/// Implements [`BitOpsMsb0`] for the primitive type by forwarding all calls to
+/// the `msb0` module of the function API.
+///
+/// [`BitOpsMsb0`]: crate::BitOpsMsb0
+macro_rules! impl_trait_msb0 {
+    ($primitive_ty:ty) => {
+        impl BitOpsMsb0 for $primitive_ty {
+            #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::msb0::set_bit`],")]
             #[doc = concat!("but as associated function (method) on `", stringify!($primitive_ty), "`.")]
             #[doc = ""] // newline needed so that markdown links work
-            #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::is_set`]: crate::bitops_", stringify!($primitive_ty), "::is_set")]
+            #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::msb0::set_bit`]: crate::bitops_", stringify!($primitive_ty), "::msb0::set_bit")]
             #[inline]
-            fn is_set(self, bit: Self) -> bool {
+            fn set_bit_msb0(self, bit: Self) -> Self {
                 paste::paste! {
-                    $crate::[< bitops _ $primitive_ty >]::is_set(self, bit)
+                    $crate::[< bitops _ $primitive_ty >]::msb0::set_bit(self, bit)
                 }
             }
 
-            #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::get_bit`],")]
+            #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::msb0::set_bit_exact`],")]
             #[doc = concat!("but as associated function (method) on `", stringify!($primitive_ty), "`.")]
             #[doc = ""] // newline needed so that markdown links work
-            #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::get_bit`]: crate::bitops_", stringify!($primitive_ty), "::get_bit")]
+            #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::msb0::set_bit_exact`]: crate::bitops_", stringify!($primitive_ty), "::msb0::set_bit_exact")]
             #[inline]
-            fn get_bit(self, bit: Self) -> Self {
+            fn set_bit_exact_msb0(self, bit: Self, value: bool) -> Self {
                 paste::paste! {
-                    $crate::[< bitops _ $primitive_ty >]::get_bit(self, bit)
+                    $crate::[< bitops _ $primitive_ty >]::msb0::set_bit_exact(self, bit, value)
                 }
             }
 
-            #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::toggle_bit`],")]
+            #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::msb0::clear_bit`],")]
             #[doc = concat!("but as associated function (method) on `", stringify!($primitive_ty), "`.")]
             #[doc = ""] // newline needed so that markdown links work
-            #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::toggle_bit`]: crate::bitops_", stringify!($primitive_ty), "::toggle_bit")]
+            #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::msb0::clear_bit`]: crate::bitops_", stringify!($primitive_ty), "::msb0::clear_bit")]
             #[inline]
-            fn toggle_bit(self, bit: Self) -> Self {
+            fn clear_bit_msb0(self, bit: Self) -> Self {
                 paste::paste! {
-                    $crate::[< bitops _ $primitive_ty >]::toggle_bit(self, bit)
+                    $crate::[< bitops _ $primitive_ty >]::msb0::clear_bit(self, bit)
                 }
             }
 
-            #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::toggle_bits`],")]
+            #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::msb0::is_set`],")]
             #[doc = concat!("but as associated function (method) on `", stringify!($primitive_ty), "`.")]
             #[doc = ""] // newline needed so that markdown links work
-            #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::toggle_bits`]: crate::bitops_", stringify!($primitive_ty), "::toggle_bits")]
+            #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::msb0::is_set`]: crate::bitops_", stringify!($primitive_ty), "::msb0::is_set")]
             #[inline]
-            fn toggle_bits(self, bits: Self, shift: Self) -> Self {
+            fn is_set_msb0(self, bit: Self) -> bool {
                 paste::paste! {
-                    $crate::[< bitops _ $primitive_ty >]::toggle_bits(self, bits, shift)
+                    $crate::[< bitops _ $primitive_ty >]::msb0::is_set(self, bit)
                 }
             }
 
-            #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::set_bits`],")]
+            #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::msb0::get_bit`],")]
             #[doc = concat!("but as associated function (method) on `", stringify!($primitive_ty), "`.")]
             #[doc = ""] // newline needed so that markdown links work
-            #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::set_bits`]: crate::bitops_", stringify!($primitive_ty), "::set_bits")]
+            #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::msb0::get_bit`]: crate::bitops_", stringify!($primitive_ty), "::msb0::get_bit")]
             #[inline]
-            fn set_bits(self, value: Self, value_bits: Self, value_shift: Self) -> Self {
+            fn get_bit_msb0(self, bit: Self) -> Self {
                 paste::paste! {
-                    $crate::[< bitops _ $primitive_ty >]::set_bits(self, value, value_bits, value_shift)
+                    $crate::[< bitops _ $primitive_ty >]::msb0::get_bit(self, bit)
                 }
             }
 
-            #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::set_bits_n`],")]
+            #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::msb0::toggle_bit`],")]
             #[doc = concat!("but as associated function (method) on `", stringify!($primitive_ty), "`.")]
             #[doc = ""] // newline needed so that markdown links work
-            #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::set_bits_n`]: crate::bitops_", stringify!($primitive_ty), "::set_bits_n")]
+            #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::msb0::toggle_bit`]: crate::bitops_", stringify!($primitive_ty), "::msb0::toggle_bit")]
             #[inline]
-            fn set_bits_n(self, ops: &[(Self, Self, Self)]) -> Self {
+            fn toggle_bit_msb0(self, bit: Self) -> Self {
                 paste::paste! {
-                    $crate::[< bitops _ $primitive_ty >]::set_bits_n(self, ops)
+                    $crate::[< bitops _ $primitive_ty >]::msb0::toggle_bit(self, bit)
                 }
             }
 
-            #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::set_bits_exact`],")]
+            #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::msb0::toggle_bits`],")]
             #[doc = concat!("but as associated function (method) on `", stringify!($primitive_ty), "`.")]
             #[doc = ""] // newline needed so that markdown links work
-            #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::set_bits_exact`]: crate::bitops_", stringify!($primitive_ty), "::set_bits_exact")]
+            #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::msb0::toggle_bits`]: crate::bitops_", stringify!($primitive_ty), "::msb0::toggle_bits")]
             #[inline]
-            fn set_bits_exact(self, value: Self, value_bits: Self, value_shift: Self) -> Self {
+            fn toggle_bits_msb0(self, first: Self, last: Self) -> Self {
                 paste::paste! {
-                    $crate::[< bitops _ $primitive_ty >]::set_bits_exact(self, value, value_bits, value_shift)
+                    $crate::[< bitops _ $primitive_ty >]::msb0::toggle_bits(self, first, last)
                 }
             }
 
-            #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::set_bits_exact_n`],")]
+            #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::msb0::set_bits`],")]
             #[doc = concat!("but as associated function (method) on `", stringify!($primitive_ty), "`.")]
             #[doc = ""] // newline needed so that markdown links work
-            #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::set_bits_exact_n`]: crate::bitops_", stringify!($primitive_ty), "::set_bits_exact_n")]
+            #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::msb0::set_bits`]: crate::bitops_", stringify!($primitive_ty), "::msb0::set_bits")]
             #[inline]
-            fn set_bits_exact_n(self, ops: &[(Self, Self, Self)]) -> Self {
+            fn set_bits_msb0(self, value: Self, first: Self, last: Self) -> Self {
                 paste::paste! {
-                    $crate::[< bitops _ $primitive_ty >]::set_bits_exact_n(self, ops)
+                    $crate::[< bitops _ $primitive_ty >]::msb0::set_bits(self, value, first, last)
                 }
             }
 
-            #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::clear_bits`],")]
+            #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::msb0::set_bits_exact`],")]
             #[doc = concat!("but as associated function (method) on `", stringify!($primitive_ty), "`.")]
             #[doc = ""] // newline needed so that markdown links work
-            #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::clear_bits`]: crate::bitops_", stringify!($primitive_ty), "::clear_bits")]
+            #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::msb0::set_bits_exact`]: crate::bitops_", stringify!($primitive_ty), "::msb0::set_bits_exact")]
             #[inline]
-            fn clear_bits(self, clear_mask: Self) -> Self {
+            fn set_bits_exact_msb0(self, value: Self, first: Self, last: Self) -> Self {
                 paste::paste! {
-                    $crate::[< bitops _ $primitive_ty >]::clear_bits(self, clear_mask)
+                    $crate::[< bitops _ $primitive_ty >]::msb0::set_bits_exact(self, value, first, last)
                 }
             }
 
-            #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::highest_bit`],")]
+            #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::msb0::get_bits`],")]
             #[doc = concat!("but as associated function (method) on `", stringify!($primitive_ty), "`.")]
             #[doc = ""] // newline needed so that markdown links work
-            #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::highest_bit`]: crate::bitops_", stringify!($primitive_ty), "::highest_bit")]
+            #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::msb0::get_bits`]: crate::bitops_", stringify!($primitive_ty), "::msb0::get_bits")]
             #[inline]
-            fn highest_bit(self) -> Option<Self> {
+            fn get_bits_msb0(self, first: Self, last: Self) -> Self {
                 paste::paste! {
-                    $crate::[< bitops _ $primitive_ty >]::highest_bit(self)
+                    $crate::[< bitops _ $primitive_ty >]::msb0::get_bits(self, first, last)
                 }
             }
 
-            #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::lowest_bit`],")]
+            #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::msb0::create_mask`],")]
             #[doc = concat!("but as associated function (method) on `", stringify!($primitive_ty), "`.")]
             #[doc = ""] // newline needed so that markdown links work
-            #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::lowest_bit`]: crate::bitops_", stringify!($primitive_ty), "::lowest_bit")]
+            #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::msb0::create_mask`]: crate::bitops_", stringify!($primitive_ty), "::msb0::create_mask")]
             #[inline]
-            fn lowest_bit(self) -> Option<Self> {
+            fn create_mask_msb0(first: Self, last: Self) -> Self {
                 paste::paste! {
-                    $crate::[< bitops _ $primitive_ty >]::lowest_bit(self)
+                    $crate::[< bitops _ $primitive_ty >]::msb0::create_mask(first, last)
                 }
             }
 
-            #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::get_bits`],")]
+            #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::msb0::highest_bit`],")]
             #[doc = concat!("but as associated function (method) on `", stringify!($primitive_ty), "`.")]
             #[doc = ""] // newline needed so that markdown links work
-            #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::get_bits`]: crate::bitops_", stringify!($primitive_ty), "::get_bits")]
+            #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::msb0::highest_bit`]: crate::bitops_", stringify!($primitive_ty), "::msb0::highest_bit")]
             #[inline]
-            fn get_bits(self, value_bits: Self, value_shift: Self) -> Self {
+            fn highest_bit_msb0(self) -> Option<Self> {
                 paste::paste! {
-                    $crate::[< bitops _ $primitive_ty >]::get_bits(self, value_bits, value_shift)
+                    $crate::[< bitops _ $primitive_ty >]::msb0::highest_bit(self)
                 }
             }
 
-            #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::create_mask`],")]
+            #[doc = concat!("Wrapper around [`bitops_", stringify!($primitive_ty), "::msb0::lowest_bit`],")]
             #[doc = concat!("but as associated function (method) on `", stringify!($primitive_ty), "`.")]
             #[doc = ""] // newline needed so that markdown links work
-            #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::create_mask`]: crate::bitops_", stringify!($primitive_ty), "::create_mask")]
+            #[doc = concat!("[`bitops_", stringify!($primitive_ty), "::msb0::lowest_bit`]: crate::bitops_", stringify!($primitive_ty), "::msb0::lowest_bit")]
             #[inline]
-            fn create_mask(bits: Self) -> Self {
+            fn lowest_bit_msb0(self) -> Option<Self> {
                 paste::paste! {
-                    $crate::[< bitops _ $primitive_ty >]::create_mask(bits)
+                    $crate::[< bitops _ $primitive_ty >]::msb0::lowest_bit(self)
                 }
             }
         }
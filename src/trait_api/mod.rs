@@ -24,194 +24,705 @@ SOFTWARE.
 #[macro_use]
 mod macros;
 
-/// Common bitwise operations to manipulate the bits in raw integers.
-pub trait BitOps: Copy + Sized {
-    /// Sets the given bit to `1`.
+use crate::BitReversePermutationIter;
+use core::num::{
+    NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize, Saturating, Wrapping,
+};
+
+const_trait! {
+    /// Common bitwise operations to manipulate the bits in raw integers.
+    pub trait BitOps: Copy + Sized {
+        /// Sets the given bit to `1`.
+        ///
+        /// The bit position starts at `0`.
+        ///
+        /// # Parameters
+        ///
+        /// - `bit`: Bit to set, starting at position `0`.
+        #[must_use]
+        fn set_bit(self, bit: Self) -> Self;
+
+        /// Sets the given bit to the given value.
+        ///
+        /// The bit position starts at `0`.
+        ///
+        /// # Parameters
+        ///
+        /// - `bit`: Bit to set, starting at position `0`.
+        /// - `value`: Value to set.
+        #[must_use]
+        fn set_bit_exact(self, bit: Self, value: bool) -> Self;
+
+        /// Clears the given bit by setting it to `0`.
+        ///
+        /// The bit position starts at `0`.
+        ///
+        /// # Parameters
+        ///
+        /// - `bit`: Bit to clear, starting at position `0`.
+        #[must_use]
+        fn clear_bit(self, bit: Self) -> Self;
+
+        /// Returns whether the given bit is set.
+        ///
+        /// The bit position starts at `0`.
+        ///
+        /// # Parameters
+        ///
+        /// - `bit`: Bit to check, starting at position `0`.
+        #[must_use]
+        fn is_set(self, bit: Self) -> bool;
+
+        /// Returns the integer value of the given bit (`0` or `1`).
+        ///
+        /// The bit position starts at `0`.
+        ///
+        /// # Parameters
+        ///
+        /// - `bit`: Bit to get, starting at position `0`.
+        #[must_use]
+        fn get_bit(self, bit: Self) -> Self;
+
+        /// Toggles (flips) the given bit.
+        ///
+        /// The bit position starts at `0`.
+        ///
+        /// # Parameters
+        ///
+        /// - `bit`: Bit to toggle, starting at position `0`.
+        #[must_use]
+        fn toggle_bit(self, bit: Self) -> Self;
+
+        /// Toggles (flips) the specified contiguous bits.
+        ///
+        /// # Parameters
+        ///
+        /// - `bits`: Amount of bits of `value` that are relevant.
+        /// - `shift`: Relevant position of bits inside `value`, starting from
+        ///   the right/LSB (`0`).
+        #[must_use]
+        fn toggle_bits(self, bits: Self, shift: Self) -> Self;
+
+        /// Sets the bits of `value` in `self` without clearing already set
+        /// bits.
+        ///
+        /// # Parameters
+        ///
+        /// - `value`: New value/bits to be set in `base`.
+        /// - `value_bits`: Amount of bits of `value` that are relevant.
+        /// - `value_shift`: Position of `value` inside `self`, starting from
+        ///   the right/LSB (`0`).
+        ///
+        /// # Panics
+        ///
+        /// This function panics for overflowing shifts and bit positions that
+        /// are outside the range of the underlying type.
+        #[must_use]
+        fn set_bits(self, value: Self, value_bits: Self, value_shift: Self) -> Self;
+
+        /// Version of [`Self::set_bits`] that applies a list of multiple values
+        /// to the base.
+        ///
+        /// # Parameters
+        ///
+        /// - `base`: Base value to alter.
+        /// - `ops`: Tuple of (`value`, `value_bits`, `value_shift`) where each
+        ///   tuple member corresponds to the parameters in [`Self::set_bits`].
+        ///
+        /// # Panics
+        ///
+        /// This function panics for overflowing shifts and bit positions that
+        /// are outside the range of the underlying type.
+        #[must_use]
+        fn set_bits_n(
+            self,
+            ops: &[(
+                Self, /* value */
+                Self, /* value_bits */
+                Self, /* value_shift */
+            )],
+        ) -> Self;
+
+        /// Like [`Self::set_bits`] but calls [`Self::clear_bits`] beforehand
+        /// for the relevant bits.
+        ///
+        /// # Parameters
+        ///
+        /// - `value`: New value/bits to be set in `base`.
+        /// - `value_bits`: Amount of bits of `value` that are relevant.
+        /// - `value_shift`: Position of `value` inside `self`, starting from
+        ///   the right/LSB (`0`).
+        ///
+        /// # Panics
+        ///
+        /// This function panics for overflowing shifts and bit positions that
+        /// are outside the range of the underlying type.
+        #[must_use]
+        fn set_bits_exact(self, value: Self, value_bits: Self, value_shift: Self) -> Self;
+
+        /// Combination of [`Self::set_bits_exact`] and [`Self::set_bits_n`].
+        ///
+        /// # Parameters
+        ///
+        /// - `base`: Base value to alter.
+        /// - `ops`: Tuple of (`value`, `value_bits`, `value_shift`) where each
+        ///   tuple member corresponds to the parameters in
+        ///   [`Self::set_bits_exact`].
+        ///
+        /// # Panics
+        ///
+        /// This function panics for overflowing shifts and bit positions that
+        /// are outside the range of the underlying type.
+        #[must_use]
+        fn set_bits_exact_n(
+            self,
+            ops: &[(
+                Self, /* value */
+                Self, /* value_bits */
+                Self, /* value_shift */
+            )],
+        ) -> Self;
+
+        /// Clears all bits specified in the mask by setting them to `0`.
+        ///
+        /// # Parameters
+        ///
+        /// - `clear_mask`: Bitmask with bits to clear.
+        #[must_use]
+        fn clear_bits(self, clear_mask: Self) -> Self;
+
+        /// Returns the highest bit that is set, if any.
+        ///
+        /// The bit position starts at `0`.
+        #[must_use]
+        fn highest_bit(self) -> Option<Self>;
+
+        /// Returns the lowest bit that is set, if any.
+        ///
+        /// The bit position starts at `0`.
+        #[must_use]
+        fn lowest_bit(self) -> Option<Self>;
+
+        /// Returns the requested contiguous bits as new integer.
+        ///
+        /// # Parameters
+        ///
+        /// - `value_bits`: Amount of bits of `value` that are relevant.
+        /// - `value_shift`: Position of `value` inside `self`, starting from
+        ///   the right/LSB (`0`).
+        #[must_use]
+        fn get_bits(self, value_bits: Self, value_shift: Self) -> Self;
+
+        /// Creates a bitmask (`1`s) with the given amount of contiguous bits.
+        ///
+        /// # Parameters
+        ///
+        /// - `bits`: Amount of contiguous bits.
+        #[must_use]
+        fn create_mask(bits: Self) -> Self;
+
+        /// Reverses the lowest `n` bits. All other bits of the result are `0`.
+        ///
+        /// # Parameters
+        ///
+        /// - `n`: Amount of low bits to reverse.
+        #[must_use]
+        fn reverse_low_bits(self, n: Self) -> Self;
+
+        /// Reverses the order of the specified contiguous bits while leaving
+        /// all other bits unchanged.
+        ///
+        /// # Parameters
+        ///
+        /// - `bits`: Amount of bits of `self` that are relevant.
+        /// - `shift`: Relevant position of bits inside `self`, starting from
+        ///   the right/LSB (`0`).
+        ///
+        /// # Panics
+        ///
+        /// This function panics for overflowing shifts and bit positions that
+        /// are outside the range of the underlying type.
+        #[must_use]
+        fn reverse_bits_in_field(self, bits: Self, shift: Self) -> Self;
+
+        /// Creates an iterator over the bit-reverse permutation of the indices
+        /// `0..2^order`.
+        ///
+        /// # Parameters
+        ///
+        /// - `order`: Amount of bits of each index.
+        #[must_use]
+        fn bit_reverse_permutation(order: Self) -> BitReversePermutationIter<Self>;
+
+        /// Converts a binary value to its (reflected binary) Gray code.
+        #[must_use]
+        fn to_gray(self) -> Self;
+
+        /// Converts a (reflected binary) Gray code back to its binary value.
+        #[must_use]
+        // Named after the Function API.
+        #[allow(clippy::wrong_self_convention)]
+        fn from_gray(self) -> Self;
+
+        /// Returns the parity, i.e., whether an odd number of bits is set.
+        #[must_use]
+        fn parity(self) -> bool;
+
+        /// Returns the parity of the specified contiguous bits.
+        ///
+        /// # Parameters
+        ///
+        /// - `bits`: Amount of bits of `self` that are relevant.
+        /// - `shift`: Relevant position of bits inside `self`, starting from
+        ///   the right/LSB (`0`).
+        #[must_use]
+        fn parity_of_field(self, bits: Self, shift: Self) -> bool;
+
+        /// Returns the number of set bits in the specified contiguous bits.
+        ///
+        /// # Parameters
+        ///
+        /// - `bits`: Amount of bits of `self` that are relevant.
+        /// - `shift`: Relevant position of bits inside `self`, starting from
+        ///   the right/LSB (`0`).
+        #[must_use]
+        fn count_ones_in_field(self, bits: Self, shift: Self) -> Self;
+
+        /// Returns the number of set bits below the given bit position.
+        ///
+        /// # Parameters
+        ///
+        /// - `pos`: Exclusive upper bit position (`0..=BITS`).
+        #[must_use]
+        fn rank(self, pos: Self) -> Self;
+
+        /// Returns the position of the `n`-th set bit (starting at `0`), if
+        /// any.
+        ///
+        /// # Parameters
+        ///
+        /// - `n`: Zero-based index of the set bit to search.
+        #[must_use]
+        fn select(self, n: Self) -> Option<Self>;
+
+        /// Aligns down to a multiple of `2^align_bits`.
+        ///
+        /// # Parameters
+        ///
+        /// - `align_bits`: Alignment as power of two, e.g., `12` for 4 KiB.
+        #[must_use]
+        fn align_down(self, align_bits: Self) -> Self;
+
+        /// Aligns up to a multiple of `2^align_bits`, wrapping around at the
+        /// boundary of the type.
+        ///
+        /// # Parameters
+        ///
+        /// - `align_bits`: Alignment as power of two, e.g., `12` for 4 KiB.
+        #[must_use]
+        fn align_up(self, align_bits: Self) -> Self;
+
+        /// Aligns up to a multiple of `2^align_bits`, if the result is
+        /// representable by the type.
+        ///
+        /// # Parameters
+        ///
+        /// - `align_bits`: Alignment as power of two, e.g., `12` for 4 KiB.
+        #[must_use]
+        fn checked_align_up(self, align_bits: Self) -> Option<Self>;
+
+        /// Returns whether the value is a multiple of `2^align_bits`.
+        ///
+        /// # Parameters
+        ///
+        /// - `align_bits`: Alignment as power of two, e.g., `12` for 4 KiB.
+        #[must_use]
+        fn is_aligned(self, align_bits: Self) -> bool;
+
+        /// Returns whether the value is a mask of contiguous low bits.
+        #[must_use]
+        fn is_power_of_two_mask(self) -> bool;
+
+        /// Returns the order `k` of the largest naturally aligned block of size
+        /// `2^k` that starts at `self` and fits into `size`, if any.
+        ///
+        /// # Parameters
+        ///
+        /// - `size`: Available size from `self` on.
+        #[must_use]
+        fn largest_aligned_block(self, size: Self) -> Option<Self>;
+
+        /// Rounds up to the next power of two, if it is representable by the
+        /// type.
+        #[must_use]
+        fn round_to_pow2(self) -> Option<Self>;
+
+        /// Rotates the specified contiguous bits to the left (towards the MSB)
+        /// by `n` bits while leaving all other bits unchanged.
+        ///
+        /// # Parameters
+        ///
+        /// - `bits`: Amount of bits of `self` that are relevant.
+        /// - `shift`: Relevant position of bits inside `self`, starting from
+        ///   the right/LSB (`0`).
+        /// - `n`: Amount of bits to rotate.
+        ///
+        /// # Panics
+        ///
+        /// This function panics for overflowing shifts and bit positions that
+        /// are outside the range of the underlying type.
+        #[must_use]
+        fn rotate_field_left(self, bits: Self, shift: Self, n: Self) -> Self;
+
+        /// Rotates the specified contiguous bits to the right (towards the LSB)
+        /// by `n` bits while leaving all other bits unchanged.
+        ///
+        /// # Parameters
+        ///
+        /// - `bits`: Amount of bits of `self` that are relevant.
+        /// - `shift`: Relevant position of bits inside `self`, starting from
+        ///   the right/LSB (`0`).
+        /// - `n`: Amount of bits to rotate.
+        ///
+        /// # Panics
+        ///
+        /// This function panics for overflowing shifts and bit positions that
+        /// are outside the range of the underlying type.
+        #[must_use]
+        fn rotate_field_right(self, bits: Self, shift: Self, n: Self) -> Self;
+
+        /// Returns a value with only the lowest set bit of `self` set.
+        ///
+        /// Returns `0` if `self` is `0`.
+        #[must_use]
+        fn isolate_lowest_set(self) -> Self;
+
+        /// Clears the lowest set bit of `self`.
+        ///
+        /// Returns `0` if `self` is `0`.
+        #[must_use]
+        fn clear_lowest_set(self) -> Self;
+
+        /// Returns a value with only the lowest cleared bit of `self` set.
+        ///
+        /// Returns `0` if all bits of `self` are set.
+        #[must_use]
+        fn isolate_lowest_zero(self) -> Self;
+
+        /// Sets all bits below the highest set bit of `self`.
+        #[must_use]
+        fn smear_right(self) -> Self;
+
+        /// Returns a mask of the lowest set bit of `self` and all bits below
+        /// it.
+        ///
+        /// If `self` is `0`, all bits are set.
+        #[must_use]
+        fn mask_up_to_lowest_set(self) -> Self;
+
+        /// Returns the next larger value with the same amount of set bits
+        /// (Gosper's hack), if any.
+        #[must_use]
+        fn next_same_popcount(self) -> Option<Self>;
+
+        /// Returns the lowest set bit at or above position `from`, if any.
+        ///
+        /// # Parameters
+        ///
+        /// - `from`: Lowest bit position to check (`0..=BITS`). For `BITS`, the
+        ///   result is always `None`.
+        ///
+        /// # Panics
+        ///
+        /// This function panics if `from` is larger than the number of bits of
+        /// the underlying type.
+        #[must_use]
+        fn next_set_bit(self, from: Self) -> Option<Self>;
+
+        /// Returns the highest set bit strictly below position `from`, if any.
+        ///
+        /// # Parameters
+        ///
+        /// - `from`: Exclusive upper bit position (`0..=BITS`). `BITS` scans
+        ///   the whole value.
+        ///
+        /// # Panics
+        ///
+        /// This function panics if `from` is larger than the number of bits of
+        /// the underlying type.
+        #[must_use]
+        fn prev_set_bit(self, from: Self) -> Option<Self>;
+
+        /// Returns the lowest cleared bit at or above position `from`, if any.
+        ///
+        /// # Parameters
+        ///
+        /// - `from`: Lowest bit position to check (`0..=BITS`). For `BITS`, the
+        ///   result is always `None`.
+        ///
+        /// # Panics
+        ///
+        /// This function panics if `from` is larger than the number of bits of
+        /// the underlying type.
+        #[must_use]
+        fn next_clear_bit(self, from: Self) -> Option<Self>;
+
+        /// Returns the highest cleared bit strictly below position `from`, if
+        /// any.
+        ///
+        /// # Parameters
+        ///
+        /// - `from`: Exclusive upper bit position (`0..=BITS`). `BITS` scans
+        ///   the whole value.
+        ///
+        /// # Panics
+        ///
+        /// This function panics if `from` is larger than the number of bits of
+        /// the underlying type.
+        #[must_use]
+        fn prev_clear_bit(self, from: Self) -> Option<Self>;
+
+        /// Checks whether all bits of `self` that are set in `mask` equal the
+        /// corresponding bits in `value`.
+        ///
+        /// The pair of `mask` and `value` is typically created by
+        /// `parse_pattern` of the Function API.
+        ///
+        /// # Parameters
+        ///
+        /// - `mask`: Relevant bits.
+        /// - `value`: Expected value of the relevant bits.
+        #[must_use]
+        fn matches_pattern(self, mask: Self, value: Self) -> bool;
+    }
+}
+
+/// Read-only subset of [`BitOps`] for the non-zero integer types, such as
+/// [`NonZeroU32`].
+///
+/// As a non-zero value always has a set bit, [`Self::highest_bit`] and
+/// [`Self::lowest_bit`] don't return an [`Option`]. Bit positions and results
+/// use the underlying primitive type, so that callers don't have to unwrap and
+/// rewrap the value.
+///
+/// # Example
+///
+/// ```rust
+/// use bit_ops::BitOpsNonZero;
+/// use core::num::NonZeroU32;
+///
+/// let id = NonZeroU32::new(0x1234).unwrap();
+/// assert_eq!(id.highest_bit(), 12);
+/// assert_eq!(id.lowest_bit(), 2);
+/// assert!(id.is_set(4));
+/// assert_eq!(id.get_bits(8, 4), 0x23);
+/// assert_eq!(id.isolate_lowest_set().get(), 0b100);
+/// ```
+pub trait BitOpsNonZero: Copy + Sized {
+    /// Underlying primitive type, used for bit positions and results.
+    type Primitive: BitOps;
+
+    /// Returns the highest bit that is set.
     ///
     /// The bit position starts at `0`.
-    ///
-    /// # Parameters
-    ///
-    /// - `bit`: Bit to set, starting at position `0`.
     #[must_use]
-    fn set_bit(self, bit: Self) -> Self;
+    fn highest_bit(self) -> Self::Primitive;
 
-    /// Sets the given bit to the given value.
+    /// Returns the lowest bit that is set.
     ///
     /// The bit position starts at `0`.
+    #[must_use]
+    fn lowest_bit(self) -> Self::Primitive;
+
+    /// Returns whether the given bit is set.
     ///
     /// # Parameters
     ///
-    /// - `bit`: Bit to set, starting at position `0`.
-    /// - `value`: Value to set.
+    /// - `bit`: Bit to check, starting at position `0`.
     #[must_use]
-    fn set_bit_exact(self, bit: Self, value: bool) -> Self;
+    fn is_set(self, bit: Self::Primitive) -> bool;
 
-    /// Clears the given bit by setting it to `0`.
-    ///
-    /// The bit position starts at `0`.
+    /// Returns the integer value of the given bit (`0` or `1`).
     ///
     /// # Parameters
     ///
-    /// - `bit`: Bit to clear, starting at position `0`.
+    /// - `bit`: Bit to get, starting at position `0`.
     #[must_use]
-    fn clear_bit(self, bit: Self) -> Self;
+    fn get_bit(self, bit: Self::Primitive) -> Self::Primitive;
 
-    /// Returns whether the given bit is set.
-    ///
-    /// The bit position starts at `0`.
+    /// Returns the requested contiguous bits as new integer.
     ///
     /// # Parameters
     ///
-    /// - `bit`: Bit to check, starting at position `0`.
+    /// - `value_bits`: Amount of bits of `value` that are relevant.
+    /// - `value_shift`: Position of `value` inside `self`, starting from the
+    ///   right/LSB (`0`).
     #[must_use]
-    fn is_set(self, bit: Self) -> bool;
+    fn get_bits(self, value_bits: Self::Primitive, value_shift: Self::Primitive)
+    -> Self::Primitive;
 
-    /// Returns the integer value of the given bit (`0` or `1`).
+    /// Returns `true` if the number of set bits is odd.
+    #[must_use]
+    fn parity(self) -> bool;
+
+    /// Keeps only the lowest set bit, which is always non-zero.
+    #[must_use]
+    fn isolate_lowest_set(self) -> Self;
+}
+
+/// MSB-0 flavour of [`BitOps`], where bit `0` is the most significant bit.
+///
+/// This is the numbering used by PowerPC, IBM mainframes, and many IETF RFC
+/// diagrams. Ranges are specified as inclusive `first..=last` bit positions.
+///
+/// The methods carry a `_msb0` suffix so that this trait and [`BitOps`] can be
+/// used side by side.
+///
+/// # Example
+///
+/// ```rust
+/// use bit_ops::BitOpsMsb0;
+///
+/// let raw = 0_u32.set_bit_msb0(0).set_bits_msb0(0b101, 29, 31);
+/// assert_eq!(raw, 0x8000_0005);
+/// assert_eq!(raw.get_bits_msb0(28, 31), 0b0101);
+/// ```
+pub trait BitOpsMsb0: Copy + Sized {
+    /// Sets the given bit to `1`.
     ///
-    /// The bit position starts at `0`.
+    /// The bit position starts at `0`, which is the most significant bit.
     ///
     /// # Parameters
     ///
-    /// - `bit`: Bit to get, starting at position `0`.
+    /// - `bit`: Bit to set, starting at position `0` (MSB).
     #[must_use]
-    fn get_bit(self, bit: Self) -> Self;
+    fn set_bit_msb0(self, bit: Self) -> Self;
 
-    /// Toggles (flips) the given bit.
+    /// Sets the given bit to the given value.
     ///
-    /// The bit position starts at `0`.
+    /// The bit position starts at `0`, which is the most significant bit.
     ///
     /// # Parameters
     ///
-    /// - `bit`: Bit to toggle, starting at position `0`.
+    /// - `bit`: Bit to set, starting at position `0` (MSB).
+    /// - `value`: Value to set.
     #[must_use]
-    fn toggle_bit(self, bit: Self) -> Self;
+    fn set_bit_exact_msb0(self, bit: Self, value: bool) -> Self;
 
-    /// Toggles (flips) the specified contiguous bits.
+    /// Clears the given bit by setting it to `0`.
+    ///
+    /// The bit position starts at `0`, which is the most significant bit.
     ///
     /// # Parameters
     ///
-    /// - `bits`: Amount of bits of `value` that are relevant.
-    /// - `shift`: Relevant position of bits inside `value`, starting from the
-    ///   right/LSB (`0`).
+    /// - `bit`: Bit to clear, starting at position `0` (MSB).
     #[must_use]
-    fn toggle_bits(self, bits: Self, shift: Self) -> Self;
+    fn clear_bit_msb0(self, bit: Self) -> Self;
 
-    /// Sets the bits of `value` in `self` without clearing already set bits.
+    /// Returns whether the given bit is set.
+    ///
+    /// The bit position starts at `0`, which is the most significant bit.
     ///
     /// # Parameters
     ///
-    /// - `value`: New value/bits to be set in `base`.
-    /// - `value_bits`: Amount of bits of `value` that are relevant.
-    /// - `value_shift`: Position of `value` inside `self`, starting from the
-    ///   right/LSB (`0`).
+    /// - `bit`: Bit to check, starting at position `0` (MSB).
+    #[must_use]
+    fn is_set_msb0(self, bit: Self) -> bool;
+
+    /// Returns the integer value of the given bit (`0` or `1`).
     ///
-    /// # Panics
+    /// The bit position starts at `0`, which is the most significant bit.
     ///
-    /// This function panics for overflowing shifts and bit positions that
-    /// are outside the range of the underlying type.
+    /// # Parameters
+    ///
+    /// - `bit`: Bit to get, starting at position `0` (MSB).
     #[must_use]
-    fn set_bits(self, value: Self, value_bits: Self, value_shift: Self) -> Self;
+    fn get_bit_msb0(self, bit: Self) -> Self;
 
-    /// Version of [`Self::set_bits`] that applies a list of multiple values to
-    /// the base.
+    /// Toggles (flips) the given bit.
+    ///
+    /// The bit position starts at `0`, which is the most significant bit.
     ///
     /// # Parameters
     ///
-    /// - `base`: Base value to alter.
-    /// - `ops`: Tuple of (`value`, `value_bits`, `value_shift`) where each
-    ///   tuple member corresponds to the parameters in [`Self::set_bits`].
+    /// - `bit`: Bit to toggle, starting at position `0` (MSB).
+    #[must_use]
+    fn toggle_bit_msb0(self, bit: Self) -> Self;
+
+    /// Toggles (flips) the bits in the inclusive range `first..=last`.
     ///
-    /// # Panics
+    /// # Parameters
     ///
-    /// This function panics for overflowing shifts and bit positions that
-    /// are outside the range of the underlying type.
+    /// - `first`: First (most significant) bit of the range, starting at
+    ///   position `0` (MSB).
+    /// - `last`: Last (least significant) bit of the range.
     #[must_use]
-    fn set_bits_n(
-        self,
-        ops: &[(
-            Self, /* value */
-            Self, /* value_bits */
-            Self, /* value_shift */
-        )],
-    ) -> Self;
+    fn toggle_bits_msb0(self, first: Self, last: Self) -> Self;
 
-    /// Like [`Self::set_bits`] but calls [`Self::clear_bits`] beforehand for
-    /// the relevant bits.
+    /// Sets the bits of `value` in the inclusive range `first..=last` without
+    /// clearing already set bits.
     ///
     /// # Parameters
     ///
-    /// - `value`: New value/bits to be set in `base`.
-    /// - `value_bits`: Amount of bits of `value` that are relevant.
-    /// - `value_shift`: Position of `value` inside `self`, starting from the
-    ///   right/LSB (`0`).
+    /// - `value`: New value/bits to be set in `self`.
+    /// - `first`: First (most significant) bit of the range, starting at
+    ///   position `0` (MSB).
+    /// - `last`: Last (least significant) bit of the range.
     ///
     /// # Panics
     ///
-    /// This function panics for overflowing shifts and bit positions that
+    /// This function panics if `first > last` and for bit positions that
     /// are outside the range of the underlying type.
     #[must_use]
-    fn set_bits_exact(self, value: Self, value_bits: Self, value_shift: Self) -> Self;
+    fn set_bits_msb0(self, value: Self, first: Self, last: Self) -> Self;
 
-    /// Combination of [`Self::set_bits_exact`] and [`Self::set_bits_n`].
+    /// Like [`Self::set_bits_msb0`] but clears the relevant bits beforehand.
     ///
     /// # Parameters
     ///
-    /// - `base`: Base value to alter.
-    /// - `ops`: Tuple of (`value`, `value_bits`, `value_shift`) where each
-    ///   tuple member corresponds to the parameters in [`Self::set_bits_exact`].
+    /// - `value`: New value/bits to be set in `self`.
+    /// - `first`: First (most significant) bit of the range, starting at
+    ///   position `0` (MSB).
+    /// - `last`: Last (least significant) bit of the range.
     ///
     /// # Panics
     ///
-    /// This function panics for overflowing shifts and bit positions that
+    /// This function panics if `first > last` and for bit positions that
     /// are outside the range of the underlying type.
     #[must_use]
-    fn set_bits_exact_n(
-        self,
-        ops: &[(
-            Self, /* value */
-            Self, /* value_bits */
-            Self, /* value_shift */
-        )],
-    ) -> Self;
+    fn set_bits_exact_msb0(self, value: Self, first: Self, last: Self) -> Self;
 
-    /// Clears all bits specified in the mask by setting them to `0`.
+    /// Returns the bits in the inclusive range `first..=last` as new integer.
     ///
     /// # Parameters
     ///
-    /// - `clear_mask`: Bitmask with bits to clear.
+    /// - `first`: First (most significant) bit of the range, starting at
+    ///   position `0` (MSB).
+    /// - `last`: Last (least significant) bit of the range.
     #[must_use]
-    fn clear_bits(self, clear_mask: Self) -> Self;
+    fn get_bits_msb0(self, first: Self, last: Self) -> Self;
 
-    /// Returns the highest bit that is set, if any.
+    /// Creates a bitmask (`1`s) covering the inclusive range `first..=last`.
     ///
-    /// The bit position starts at `0`.
-    #[must_use]
-    fn highest_bit(self) -> Option<Self>;
-
-    /// Returns the lowest bit that is set, if any.
+    /// # Parameters
     ///
-    /// The bit position starts at `0`.
+    /// - `first`: First (most significant) bit of the range, starting at
+    ///   position `0` (MSB).
+    /// - `last`: Last (least significant) bit of the range.
     #[must_use]
-    fn lowest_bit(self) -> Option<Self>;
+    fn create_mask_msb0(first: Self, last: Self) -> Self;
 
-    /// Returns the requested contiguous bits as new integer.
-    ///
-    /// # Parameters
+    /// Returns the highest (most significant) bit that is set, if any.
     ///
-    /// - `value_bits`: Amount of bits of `value` that are relevant.
-    /// - `value_shift`: Position of `value` inside `self`, starting from the
-    ///   right/LSB (`0`).
+    /// The bit position starts at `0`, which is the most significant bit.
     #[must_use]
-    fn get_bits(self, value_bits: Self, value_shift: Self) -> Self;
+    fn highest_bit_msb0(self) -> Option<Self>;
 
-    /// Creates a bitmask (`1`s) with the given amount of contiguous bits.
-    ///
-    /// # Parameters
+    /// Returns the lowest (least significant) bit that is set, if any.
     ///
-    /// - `bits`: Amount of contiguous bits.
+    /// The bit position starts at `0`, which is the most significant bit.
     #[must_use]
-    fn create_mask(bits: Self) -> Self;
+    fn lowest_bit_msb0(self) -> Option<Self>;
 }
 
 impl_trait!(u8);
@@ -220,3 +731,82 @@ impl_trait!(u32);
 impl_trait!(u64);
 impl_trait!(u128);
 impl_trait!(usize);
+
+impl_trait_wrapper!(Wrapping, u8);
+impl_trait_wrapper!(Wrapping, u16);
+impl_trait_wrapper!(Wrapping, u32);
+impl_trait_wrapper!(Wrapping, u64);
+impl_trait_wrapper!(Wrapping, u128);
+impl_trait_wrapper!(Wrapping, usize);
+
+impl_trait_wrapper!(Saturating, u8);
+impl_trait_wrapper!(Saturating, u16);
+impl_trait_wrapper!(Saturating, u32);
+impl_trait_wrapper!(Saturating, u64);
+impl_trait_wrapper!(Saturating, u128);
+impl_trait_wrapper!(Saturating, usize);
+
+impl_trait_nonzero!(NonZeroU8, u8);
+impl_trait_nonzero!(NonZeroU16, u16);
+impl_trait_nonzero!(NonZeroU32, u32);
+impl_trait_nonzero!(NonZeroU64, u64);
+impl_trait_nonzero!(NonZeroU128, u128);
+impl_trait_nonzero!(NonZeroUsize, usize);
+
+impl_trait_msb0!(u8);
+impl_trait_msb0!(u16);
+impl_trait_msb0!(u32);
+impl_trait_msb0!(u64);
+impl_trait_msb0!(u128);
+impl_trait_msb0!(usize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::vec::Vec;
+
+    #[test]
+    fn wrapper_types() {
+        let counter = Wrapping(0_u16).set_bit(Wrapping(3)).set_bit(Wrapping(15));
+        assert_eq!(counter, Wrapping(0x8008));
+        assert_eq!(counter.highest_bit(), Some(Wrapping(15)));
+        assert_eq!(counter + Wrapping(0x8000), Wrapping(8));
+        assert_eq!(
+            counter.set_bits_exact_n(&[(Wrapping(0xf), Wrapping(4), Wrapping(4))]),
+            Wrapping(0x80f8)
+        );
+        assert_eq!(
+            Wrapping::<u8>::bit_reverse_permutation(Wrapping(2)).collect::<Vec<_>>(),
+            [0, 2, 1, 3].map(Wrapping)
+        );
+
+        let value = Saturating(0_u32).set_bits(Saturating(0b101), Saturating(3), Saturating(4));
+        assert_eq!(value, Saturating(0b101_0000));
+        assert_eq!(value.lowest_bit(), Some(Saturating(4)));
+        assert_eq!(Saturating(0_u64).highest_bit(), None);
+        assert_eq!(Saturating(u8::MAX).round_to_pow2(), None);
+    }
+
+    #[test]
+    fn nonzero_types() {
+        let id = NonZeroU8::new(0b1010_0000).unwrap();
+        assert_eq!(id.highest_bit(), 7);
+        assert_eq!(id.lowest_bit(), 5);
+        assert!(id.is_set(5));
+        assert!(!id.is_set(4));
+        assert_eq!(id.get_bit(7), 1);
+        assert_eq!(id.get_bits(3, 5), 0b101);
+        assert!(!id.parity());
+        assert_eq!(id.isolate_lowest_set(), NonZeroU8::new(0b10_0000).unwrap());
+
+        assert_eq!(NonZeroU128::MAX.highest_bit(), 127);
+        assert_eq!(NonZeroUsize::MIN.highest_bit(), 0);
+
+        for value in 1..=u8::MAX {
+            let id = NonZeroU8::new(value).unwrap();
+            assert_eq!(id.isolate_lowest_set().get(), value.isolate_lowest_set());
+        }
+        let id = NonZeroU128::new(1 << 127).unwrap();
+        assert_eq!(id.isolate_lowest_set(), id);
+    }
+}
@@ -0,0 +1,432 @@
+//! Module providing variable-length integer encodings.
+//!
+//! - LEB128 (unsigned and signed), as used by DWARF, WebAssembly, and
+//!   Protocol Buffers: Each byte carries 7 bits of the value, starting with
+//!   the least significant group. The MSB of each byte signals whether
+//!   another byte follows.
+//! - Zigzag: Maps signed integers to unsigned ones so that values with a small
+//!   magnitude have a small encoding, e.g., `0, -1, 1, -2` map to `0, 1, 2, 3`.
+//! - Prefix varint: The amount of leading zeroes of the first byte determines
+//!   the total length, so that the length is known after looking at a single
+//!   byte. See [`encode_prefix_varint`].
+//!
+//! All functions are `const` and operate on caller-provided buffers.
+
+use core::fmt::{self, Display, Formatter};
+
+/// Maximum length of a LEB128-encoded 64-bit integer in bytes.
+pub const MAX_LEB128_LEN: usize = 10;
+
+/// Maximum length of a prefix-varint-encoded 64-bit integer in bytes.
+pub const MAX_PREFIX_VARINT_LEN: usize = 9;
+
+/// Errors of the encoding and decoding functions of this module.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VarintError {
+    /// The input ended before the end of the encoded value.
+    Truncated,
+    /// The encoded value doesn't fit into the target type.
+    Overflow,
+    /// The output buffer is too small for the encoded value.
+    BufferTooSmall,
+}
+
+impl Display for VarintError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Truncated => f.write_str("input ended before the end of the encoded value"),
+            Self::Overflow => f.write_str("encoded value doesn't fit into the target type"),
+            Self::BufferTooSmall => f.write_str("output buffer is too small"),
+        }
+    }
+}
+
+impl core::error::Error for VarintError {}
+
+/// Returns the length of the unsigned LEB128 encoding of `value` in bytes.
+#[must_use]
+pub const fn uleb128_len(value: u64) -> usize {
+    let bits = u64::BITS - (value | 1).leading_zeros();
+    bits.div_ceil(7) as usize
+}
+
+/// Encodes `value` as unsigned LEB128 into `out`.
+///
+/// Returns the amount of written bytes.
+///
+/// # Example
+///
+/// ```rust
+/// use bit_ops::varint::{decode_uleb128, encode_uleb128};
+///
+/// let mut buf = [0; 10];
+/// assert_eq!(encode_uleb128(624485, &mut buf), Ok(3));
+/// assert_eq!(&buf[..3], &[0xe5, 0x8e, 0x26]);
+/// assert_eq!(decode_uleb128(&buf), Ok((624485, 3)));
+/// ```
+///
+/// # Errors
+///
+/// - [`VarintError::BufferTooSmall`] if `out` is too small for the encoded
+///   value.
+pub const fn encode_uleb128(value: u64, out: &mut [u8]) -> Result<usize, VarintError> {
+    let len = uleb128_len(value);
+    if out.len() < len {
+        return Err(VarintError::BufferTooSmall);
+    }
+    let mut value = value;
+    let mut i = 0;
+    while i < len - 1 {
+        out[i] = (value as u8 & 0x7f) | 0x80;
+        value >>= 7;
+        i += 1;
+    }
+    out[i] = value as u8;
+    Ok(len)
+}
+
+/// Decodes an unsigned LEB128 value from the beginning of `input`.
+///
+/// Returns the value and the amount of consumed bytes.
+///
+/// # Errors
+///
+/// - [`VarintError::Truncated`] if `input` ends within the value.
+/// - [`VarintError::Overflow`] if the value doesn't fit into a [`u64`].
+pub const fn decode_uleb128(input: &[u8]) -> Result<(u64, usize), VarintError> {
+    let mut value = 0_u64;
+    let mut shift = 0;
+    let mut i = 0;
+    while i < input.len() {
+        let byte = input[i];
+        if shift == 63 && byte > 1 {
+            return Err(VarintError::Overflow);
+        }
+        value |= ((byte & 0x7f) as u64) << shift;
+        if byte & 0x80 == 0 {
+            return Ok((value, i + 1));
+        }
+        shift += 7;
+        i += 1;
+    }
+    Err(VarintError::Truncated)
+}
+
+/// Encodes `value` as signed LEB128 into `out`.
+///
+/// Returns the amount of written bytes.
+///
+/// # Example
+///
+/// ```rust
+/// use bit_ops::varint::{decode_sleb128, encode_sleb128};
+///
+/// let mut buf = [0; 10];
+/// assert_eq!(encode_sleb128(-123456, &mut buf), Ok(3));
+/// assert_eq!(&buf[..3], &[0xc0, 0xbb, 0x78]);
+/// assert_eq!(decode_sleb128(&buf), Ok((-123456, 3)));
+/// ```
+///
+/// # Errors
+///
+/// - [`VarintError::BufferTooSmall`] if `out` is too small for the encoded
+///   value.
+pub const fn encode_sleb128(value: i64, out: &mut [u8]) -> Result<usize, VarintError> {
+    let mut value = value;
+    let mut i = 0;
+    loop {
+        let mut byte = value as u8 & 0x7f;
+        value >>= 7;
+        let sign_bit = byte & 0x40 != 0;
+        let done = (value == 0 && !sign_bit) || (value == -1 && sign_bit);
+        if !done {
+            byte |= 0x80;
+        }
+        if i == out.len() {
+            return Err(VarintError::BufferTooSmall);
+        }
+        out[i] = byte;
+        i += 1;
+        if done {
+            return Ok(i);
+        }
+    }
+}
+
+/// Decodes a signed LEB128 value from the beginning of `input`.
+///
+/// Returns the value and the amount of consumed bytes.
+///
+/// # Errors
+///
+/// - [`VarintError::Truncated`] if `input` ends within the value.
+/// - [`VarintError::Overflow`] if the value doesn't fit into an [`i64`].
+pub const fn decode_sleb128(input: &[u8]) -> Result<(i64, usize), VarintError> {
+    let mut value = 0_i64;
+    let mut shift = 0;
+    let mut i = 0;
+    while i < input.len() {
+        let byte = input[i];
+        // The last possible byte only holds the sign bit and its extension.
+        if shift == 63 && byte != 0x00 && byte != 0x7f {
+            return Err(VarintError::Overflow);
+        }
+        value |= ((byte & 0x7f) as i64) << shift;
+        shift += 7;
+        if byte & 0x80 == 0 {
+            if shift < 64 && byte & 0x40 != 0 {
+                value |= -1 << shift;
+            }
+            return Ok((value, i + 1));
+        }
+        i += 1;
+    }
+    Err(VarintError::Truncated)
+}
+
+/// Implements the zigzag functions for the specified signed and unsigned
+/// primitive types of the same width.
+macro_rules! impl_zigzag {
+    ($signed_ty:ty, $unsigned_ty:ty) => {
+        paste::paste! {
+            /// Maps a signed integer to an unsigned one so that values with a
+            /// small magnitude have small results.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use bit_ops::varint::zigzag_encode_", stringify!($signed_ty), ";")]
+            ///
+            #[doc = concat!("assert_eq!(zigzag_encode_", stringify!($signed_ty), "(0), 0);")]
+            #[doc = concat!("assert_eq!(zigzag_encode_", stringify!($signed_ty), "(-1), 1);")]
+            #[doc = concat!("assert_eq!(zigzag_encode_", stringify!($signed_ty), "(1), 2);")]
+            #[doc = concat!("assert_eq!(zigzag_encode_", stringify!($signed_ty), "(", stringify!($signed_ty), "::MIN), ", stringify!($unsigned_ty), "::MAX);")]
+            /// ```
+            #[must_use]
+            #[inline]
+            pub const fn [< zigzag_encode_ $signed_ty >](value: $signed_ty) -> $unsigned_ty {
+                ((value << 1) ^ (value >> (<$signed_ty>::BITS - 1))) as $unsigned_ty
+            }
+
+            /// Inverse of
+            #[doc = concat!("[`zigzag_encode_", stringify!($signed_ty), "`].")]
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use bit_ops::varint::zigzag_decode_", stringify!($signed_ty), ";")]
+            ///
+            #[doc = concat!("assert_eq!(zigzag_decode_", stringify!($signed_ty), "(0), 0);")]
+            #[doc = concat!("assert_eq!(zigzag_decode_", stringify!($signed_ty), "(1), -1);")]
+            #[doc = concat!("assert_eq!(zigzag_decode_", stringify!($signed_ty), "(2), 1);")]
+            /// ```
+            #[must_use]
+            #[inline]
+            pub const fn [< zigzag_decode_ $signed_ty >](value: $unsigned_ty) -> $signed_ty {
+                ((value >> 1) as $signed_ty) ^ -((value & 1) as $signed_ty)
+            }
+        }
+    };
+}
+
+impl_zigzag!(i8, u8);
+impl_zigzag!(i16, u16);
+impl_zigzag!(i32, u32);
+impl_zigzag!(i64, u64);
+impl_zigzag!(i128, u128);
+impl_zigzag!(isize, usize);
+
+/// Returns the length of the prefix varint encoding of `value` in bytes.
+#[must_use]
+pub const fn prefix_varint_len(value: u64) -> usize {
+    let bits = u64::BITS - (value | 1).leading_zeros();
+    if bits > 56 {
+        MAX_PREFIX_VARINT_LEN
+    } else {
+        bits.div_ceil(7) as usize
+    }
+}
+
+/// Encodes `value` as prefix varint into `out`.
+///
+/// Returns the amount of written bytes.
+///
+/// A value with a length of `n <= 8` bytes starts with `n - 1` zero bits
+/// followed by a one bit, and the remaining `7 * n` bits hold the value in
+/// big-endian order. Values with more than 56 bits are encoded as a zero byte
+/// followed by all 8 bytes of the value in big-endian order. Thus, the decoder
+/// knows the length from the leading zeroes of the first byte.
+///
+/// # Example
+///
+/// ```rust
+/// use bit_ops::varint::{decode_prefix_varint, encode_prefix_varint};
+///
+/// let mut buf = [0; 9];
+/// assert_eq!(encode_prefix_varint(0x7f, &mut buf), Ok(1));
+/// assert_eq!(&buf[..1], &[0xff]);
+/// assert_eq!(encode_prefix_varint(0x1234, &mut buf), Ok(2));
+/// assert_eq!(&buf[..2], &[0x52, 0x34]);
+/// assert_eq!(decode_prefix_varint(&buf), Ok((0x1234, 2)));
+/// ```
+///
+/// # Errors
+///
+/// - [`VarintError::BufferTooSmall`] if `out` is too small for the encoded
+///   value.
+pub const fn encode_prefix_varint(value: u64, out: &mut [u8]) -> Result<usize, VarintError> {
+    let len = prefix_varint_len(value);
+    if out.len() < len {
+        return Err(VarintError::BufferTooSmall);
+    }
+    let bytes = value.to_be_bytes();
+    if len == MAX_PREFIX_VARINT_LEN {
+        out[0] = 0;
+        let mut i = 0;
+        while i < bytes.len() {
+            out[i + 1] = bytes[i];
+            i += 1;
+        }
+    } else {
+        let mut i = 0;
+        while i < len {
+            out[i] = bytes[bytes.len() - len + i];
+            i += 1;
+        }
+        // Length marker
+        out[0] |= 0x80 >> (len - 1);
+    }
+    Ok(len)
+}
+
+/// Decodes a prefix varint value from the beginning of `input`.
+///
+/// Returns the value and the amount of consumed bytes. See
+/// [`encode_prefix_varint`] for the format.
+///
+/// # Errors
+///
+/// - [`VarintError::Truncated`] if `input` ends within the value.
+pub const fn decode_prefix_varint(input: &[u8]) -> Result<(u64, usize), VarintError> {
+    if input.is_empty() {
+        return Err(VarintError::Truncated);
+    }
+    let len = input[0].leading_zeros() as usize + 1;
+    if input.len() < len {
+        return Err(VarintError::Truncated);
+    }
+    let mut value = 0_u64;
+    let mut i = 0;
+    while i < len {
+        value = (value << 8) | input[i] as u64;
+        i += 1;
+    }
+    if len < MAX_PREFIX_VARINT_LEN {
+        // Remove the length marker.
+        value &= !(1 << (7 * len));
+    }
+    Ok((value, len))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Interesting test values around the boundaries of the encodings.
+    fn values() -> impl Iterator<Item = u64> {
+        (0..64)
+            .flat_map(|bit| {
+                let value = 1_u64 << bit;
+                [value - 1, value, value + 1]
+            })
+            .chain([u64::MAX, 0x0123_4567_89ab_cdef])
+    }
+
+    #[test]
+    fn uleb128() {
+        let mut buf = [0; MAX_LEB128_LEN];
+        for value in values() {
+            let len = encode_uleb128(value, &mut buf).unwrap();
+            assert_eq!(len, uleb128_len(value));
+            assert_eq!(decode_uleb128(&buf[..len]), Ok((value, len)));
+            assert_eq!(decode_uleb128(&buf[..len - 1]), Err(VarintError::Truncated));
+            assert_eq!(
+                encode_uleb128(value, &mut buf[..len - 1]),
+                Err(VarintError::BufferTooSmall)
+            );
+        }
+        assert_eq!(uleb128_len(u64::MAX), MAX_LEB128_LEN);
+
+        // Non-canonical encodings with padding are accepted.
+        assert_eq!(decode_uleb128(&[0x80, 0x80, 0x00]), Ok((0, 3)));
+        assert_eq!(
+            decode_uleb128(&[0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x02]),
+            Err(VarintError::Overflow)
+        );
+        assert_eq!(
+            decode_uleb128(&[0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x81]),
+            Err(VarintError::Overflow)
+        );
+    }
+
+    #[test]
+    fn sleb128() {
+        let mut buf = [0; MAX_LEB128_LEN];
+        for value in values().flat_map(|value| [value as i64, (value as i64).wrapping_neg()]) {
+            let len = encode_sleb128(value, &mut buf).unwrap();
+            assert_eq!(decode_sleb128(&buf[..len]), Ok((value, len)), "{value}");
+            assert_eq!(decode_sleb128(&buf[..len - 1]), Err(VarintError::Truncated));
+            assert_eq!(
+                encode_sleb128(value, &mut buf[..len - 1]),
+                Err(VarintError::BufferTooSmall)
+            );
+        }
+        assert_eq!(encode_sleb128(i64::MIN, &mut buf), Ok(MAX_LEB128_LEN));
+        assert_eq!(encode_sleb128(63, &mut buf), Ok(1));
+        assert_eq!(encode_sleb128(64, &mut buf), Ok(2));
+        assert_eq!(encode_sleb128(-64, &mut buf), Ok(1));
+        assert_eq!(encode_sleb128(-65, &mut buf), Ok(2));
+        assert_eq!(
+            decode_sleb128(&[0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x01]),
+            Err(VarintError::Overflow)
+        );
+    }
+
+    #[test]
+    fn zigzag() {
+        for value in i8::MIN..=i8::MAX {
+            let encoded = zigzag_encode_i8(value);
+            let expected = if value >= 0 {
+                value as u8 * 2
+            } else {
+                (-(value as i16) * 2 - 1) as u8
+            };
+            assert_eq!(encoded, expected);
+            assert_eq!(zigzag_decode_i8(encoded), value);
+        }
+        for value in [0, 1, -1, i128::MAX, i128::MIN] {
+            assert_eq!(zigzag_decode_i128(zigzag_encode_i128(value)), value);
+        }
+        assert_eq!(zigzag_encode_i64(i64::MAX), u64::MAX - 1);
+    }
+
+    #[test]
+    fn prefix_varint() {
+        let mut buf = [0; MAX_PREFIX_VARINT_LEN];
+        for value in values() {
+            let len = encode_prefix_varint(value, &mut buf).unwrap();
+            assert_eq!(len, prefix_varint_len(value));
+            assert_eq!(decode_prefix_varint(&buf[..len]), Ok((value, len)));
+            assert_eq!(
+                decode_prefix_varint(&buf[..len - 1]),
+                Err(VarintError::Truncated)
+            );
+            assert_eq!(
+                encode_prefix_varint(value, &mut buf[..len - 1]),
+                Err(VarintError::BufferTooSmall)
+            );
+        }
+        assert_eq!(prefix_varint_len((1 << 56) - 1), 8);
+        assert_eq!(prefix_varint_len(1 << 56), MAX_PREFIX_VARINT_LEN);
+    }
+}
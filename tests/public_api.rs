@@ -1,5 +1,7 @@
 //! This is just a simple test ensuring the public API works as expected.
 
+#![cfg_attr(feature = "nightly-const-trait", feature(const_trait_impl))]
+
 use bit_ops::{BitOps, bitops_u64};
 
 #[test]
@@ -32,8 +34,113 @@ fn const_compatible() {
         let _ = lowest_bit(0);
         let _ = get_bits(0, 0, 0);
         let _ = create_mask(0);
+        let _ = msb0::set_bit(0, 0);
+        let _ = msb0::get_bits(0, 0, 0);
+        let _ = parse_pattern(
+            "xxxxxxxx_xxxxxxxx_xxxxxxxx_xxxxxxxx_xxxxxxxx_xxxxxxxx_xxxxxxxx_xxxxxxxx",
+        );
+        let _ =
+            pattern_mask("xxxxxxxx_xxxxxxxx_xxxxxxxx_xxxxxxxx_xxxxxxxx_xxxxxxxx_xxxxxxxx_xxxxxxxx");
+        let _ = pattern_value(
+            "xxxxxxxx_xxxxxxxx_xxxxxxxx_xxxxxxxx_xxxxxxxx_xxxxxxxx_xxxxxxxx_xxxxxxxx",
+        );
+        let _ = matches_pattern(0, 0, 0);
+        let _ = longest_run_of_ones(0);
+        let _ = run_of_ones_at(0, 0);
+        let _ = first_run_of_zeros_with_len(0, 0);
+        let _ = count_runs(0);
+        let _ = next_set_bit(0, 0);
+        let _ = prev_set_bit(0, 0);
+        let _ = next_clear_bit(0, 0);
+        let _ = prev_clear_bit(0, 0);
+        let _ = isolate_lowest_set(0);
+        let _ = clear_lowest_set(0);
+        let _ = isolate_lowest_zero(0);
+        let _ = smear_right(0);
+        let _ = mask_up_to_lowest_set(0);
+        let _ = next_same_popcount(0);
+        let _ = rotate_field_left(0, 0, 0, 0);
+        let _ = rotate_field_right(0, 0, 0, 0);
+        let _ = align_down(0, 0);
+        let _ = align_up(0, 0);
+        let _ = checked_align_up(0, 0);
+        let _ = is_aligned(0, 0);
+        let _ = is_power_of_two_mask(0);
+        let _ = largest_aligned_block(0, 0);
+        let _ = round_to_pow2(0);
+        let _ = rank(0, 0);
+        let _ = select(0, 0);
+        let _ = to_gray(0);
+        let _ = from_gray(0);
+        let _ = parity(0);
+        let _ = parity_of_field(0, 0, 0);
+        let _ = count_ones_in_field(0, 0, 0);
+        let _ = interleave_bits(0, 0);
+        let _ = deinterleave_bits(0);
+        let _ = interleave_bits_3d(0, 0, 0);
+        let _ = deinterleave_bits_3d(0);
+        let _ = reverse_low_bits(0, 0);
+        let _ = reverse_bits_in_field(0, 0, 0);
+        let _ = bit_reverse_permutation(0);
+    }
+    compiles();
+}
+
+#[cfg(feature = "nightly-const-trait")]
+#[test]
+fn const_compatible_trait() {
+    const fn compiles() {
+        let _ = 0_u64.set_bit(0);
+        let _ = 0_u64.set_bit_exact(0, false);
+        let _ = 0_u64.clear_bit(0);
+        let _ = 0_u64.is_set(0);
+        let _ = 0_u64.get_bit(0);
+        let _ = 0_u64.toggle_bit(0);
+        let _ = 0_u64.toggle_bits(0, 0);
+        let _ = 0_u64.set_bits(0, 0, 0);
+        let _ = 0_u64.set_bits_n(&[]);
+        let _ = 0_u64.set_bits_exact(0, 0, 0);
+        let _ = 0_u64.set_bits_exact_n(&[]);
+        let _ = 0_u64.clear_bits(0);
+        let _ = 0_u64.highest_bit();
+        let _ = 0_u64.lowest_bit();
+        let _ = 0_u64.get_bits(0, 0);
+        let _ = u64::create_mask(0);
+        let _ = 0_u64.reverse_low_bits(0);
+        let _ = 0_u64.reverse_bits_in_field(0, 0);
+        let _ = u64::bit_reverse_permutation(0);
+        let _ = 0_u64.to_gray();
+        let _ = 0_u64.from_gray();
+        let _ = 0_u64.parity();
+        let _ = 0_u64.parity_of_field(0, 0);
+        let _ = 0_u64.count_ones_in_field(0, 0);
+        let _ = 0_u64.rank(0);
+        let _ = 0_u64.select(0);
+        let _ = 0_u64.align_down(0);
+        let _ = 0_u64.align_up(0);
+        let _ = 0_u64.checked_align_up(0);
+        let _ = 0_u64.is_aligned(0);
+        let _ = 0_u64.is_power_of_two_mask();
+        let _ = 0_u64.largest_aligned_block(0);
+        let _ = 0_u64.round_to_pow2();
+        let _ = 0_u64.rotate_field_left(0, 0, 0);
+        let _ = 0_u64.rotate_field_right(0, 0, 0);
+        let _ = 0_u64.isolate_lowest_set();
+        let _ = 0_u64.clear_lowest_set();
+        let _ = 0_u64.isolate_lowest_zero();
+        let _ = 0_u64.smear_right();
+        let _ = 0_u64.mask_up_to_lowest_set();
+        let _ = 0_u64.next_same_popcount();
+        let _ = 0_u64.next_set_bit(0);
+        let _ = 0_u64.prev_set_bit(0);
+        let _ = 0_u64.next_clear_bit(0);
+        let _ = 0_u64.prev_clear_bit(0);
+        let _ = 0_u64.matches_pattern(0, 0);
     }
     compiles();
+
+    const RAW: u64 = 0_u64.set_bit(1).set_bit(2).set_bits_exact(0xf, 4, 60);
+    assert_eq!(RAW, 0xf000_0000_0000_0006);
 }
 
 #[test]